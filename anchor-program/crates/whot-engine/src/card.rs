@@ -0,0 +1,226 @@
+use crate::deck::{CIRCLE, CROSS, SQUARE, STAR, TRIANGLE, WHOT};
+use crate::error::{EngineError, Result};
+
+pub const WHOT_ID: u8 = Shape::Whot as u8;
+pub const STAR_ID: u8 = Shape::Star as u8;
+pub const WHOT_NUMBER: u8 = 20;
+/// Shape id of a face-down card, see [`Card::hidden`]
+pub const HIDDEN_ID: u8 = 0;
+// packed face-down cards use the two shape ids no real card has
+const PACKED_HIDDEN_LOW: u8 = HIDDEN_ID;
+const PACKED_HIDDEN_HIGH: u8 = 7;
+
+/// Card shapes, the discriminant is the `id` stored in a [`Card`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum Shape {
+    Whot = 1,
+    Circle = 2,
+    Triangle = 3,
+    Cross = 4,
+    Square = 5,
+    Star = 6,
+}
+
+impl Shape {
+    pub const ALL: [Shape; 6] = [
+        Shape::Whot,
+        Shape::Circle,
+        Shape::Triangle,
+        Shape::Cross,
+        Shape::Square,
+        Shape::Star,
+    ];
+
+    /// Shapes a WHOT card can ask for
+    pub const NEEDABLE: [Shape; 5] = [
+        Shape::Circle,
+        Shape::Triangle,
+        Shape::Cross,
+        Shape::Square,
+        Shape::Star,
+    ];
+
+    pub fn from_id(id: u8) -> Option<Shape> {
+        Shape::ALL.into_iter().find(|shape| *shape as u8 == id)
+    }
+
+    pub fn id(self) -> u8 {
+        self as u8
+    }
+
+    /// Card numbers of this shape in the deck, WHOT cards repeat
+    pub fn numbers(self) -> &'static [u8] {
+        match self {
+            Shape::Whot => &WHOT,
+            Shape::Circle => &CIRCLE,
+            Shape::Triangle => &TRIANGLE,
+            Shape::Cross => &CROSS,
+            Shape::Square => &SQUARE,
+            Shape::Star => &STAR,
+        }
+    }
+
+    pub fn is_needable(self) -> bool {
+        self != Shape::Whot
+    }
+}
+
+/// A card is a shape `id` (see [`Shape`]) and a `card_number`.
+///
+/// The two byte layout is what clients send and what older program
+/// versions stored; [`Card::pack`] gives the one byte storage encoding.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Card {
+    pub id: u8,
+    pub card_number: u8,
+}
+
+impl Card {
+    pub fn new(id: u8, card_number: u8) -> Self {
+        Card { id, card_number }
+    }
+
+    /// Build a card, rejecting anything that is not part of the deck
+    pub fn try_new(id: u8, card_number: u8) -> Result<Self> {
+        let card = Card::new(id, card_number);
+        if !card.is_valid() {
+            return Err(EngineError::InvalidCard);
+        }
+        Ok(card)
+    }
+
+    pub fn of(shape: Shape, card_number: u8) -> Result<Self> {
+        Card::try_new(shape.id(), card_number)
+    }
+
+    /// Face-down card at deck `position`, used for hidden hands where only
+    /// a commitment to the card is known until it is revealed
+    pub fn hidden(position: u8) -> Self {
+        Card::new(HIDDEN_ID, position)
+    }
+
+    pub fn is_hidden(&self) -> bool {
+        self.id == HIDDEN_ID
+    }
+
+    /// Deck position of a face-down card
+    pub fn hidden_position(&self) -> Option<u8> {
+        self.is_hidden().then_some(self.card_number)
+    }
+
+    pub fn shape(&self) -> Option<Shape> {
+        Shape::from_id(self.id)
+    }
+
+    /// Whether the card exists in the deck definition
+    pub fn is_valid(&self) -> bool {
+        self.shape()
+            .is_some_and(|shape| shape.numbers().contains(&self.card_number))
+    }
+
+    pub fn is_whot(&self) -> bool {
+        self.shape() == Some(Shape::Whot)
+    }
+
+    /// Value of the card when hands are counted
+    pub fn value(&self, star_double: bool) -> u8 {
+        if self.shape() == Some(Shape::Star) && star_double {
+            self.card_number.saturating_mul(2)
+        } else {
+            self.card_number
+        }
+    }
+
+    /// One byte encoding, shape id in the top 3 bits and the card number
+    /// (at most 20) in the low 5 bits. Face-down cards split their deck
+    /// position over the two unused shape ids.
+    pub fn pack(&self) -> u8 {
+        if let Some(position) = self.hidden_position() {
+            return match position {
+                0..=31 => (PACKED_HIDDEN_LOW << 5) | position,
+                _ => (PACKED_HIDDEN_HIGH << 5) | (position - 32),
+            };
+        }
+        (self.id << 5) | (self.card_number & 0x1f)
+    }
+
+    /// Decode a packed card without checking it against the deck
+    pub fn from_packed(byte: u8) -> Card {
+        match byte >> 5 {
+            PACKED_HIDDEN_HIGH => Card::hidden(32 + (byte & 0x1f)),
+            id => Card::new(id, byte & 0x1f),
+        }
+    }
+
+    /// Decode a packed card, `None` if the byte is not a deck card
+    pub fn unpack(byte: u8) -> Option<Card> {
+        Some(Card::from_packed(byte)).filter(Card::is_valid)
+    }
+
+    /// Legacy two byte Borsh layout, `[id, card_number]`
+    pub fn to_bytes(&self) -> [u8; 2] {
+        [self.id, self.card_number]
+    }
+
+    pub fn from_bytes(bytes: [u8; 2]) -> Self {
+        Card::new(bytes[0], bytes[1])
+    }
+}
+
+pub fn pack_cards(cards: &[Card]) -> Vec<u8> {
+    cards.iter().map(Card::pack).collect()
+}
+
+/// Decode packed cards, failing on the first byte that is not a deck card
+pub fn unpack_cards(bytes: &[u8]) -> Result<Vec<Card>> {
+    bytes
+        .iter()
+        .map(|byte| Card::unpack(*byte).ok_or(EngineError::InvalidCard))
+        .collect()
+}
+
+/// Convert a legacy two bytes per card pile into the packed encoding
+pub fn repack_legacy(bytes: &[u8]) -> Result<Vec<u8>> {
+    bytes
+        .chunks_exact(2)
+        .map(|pair| {
+            let card = Card::try_new(pair[0], pair[1])?;
+            Ok(card.pack())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deck::{full_deck, DECK_SIZE};
+
+    #[test]
+    fn every_deck_card_round_trips_through_one_byte() {
+        for card in full_deck() {
+            assert!(card.is_valid());
+            assert_eq!(Card::unpack(card.pack()), Some(card));
+            assert_eq!(Card::from_bytes(card.to_bytes()), card);
+        }
+        assert_eq!(Card::unpack(0), None);
+        for position in 0..DECK_SIZE as u8 {
+            let card = Card::hidden(position);
+            assert_eq!(Card::from_packed(card.pack()), card);
+            assert_eq!(Card::unpack(card.pack()), None);
+        }
+        assert_eq!(Card::unpack(Card::new(4, 4).pack()), None);
+    }
+
+    #[test]
+    fn validated_constructors() {
+        assert_eq!(Card::of(Shape::Star, 8), Ok(Card::new(6, 8)));
+        assert_eq!(Card::of(Shape::Star, 10), Err(EngineError::InvalidCard));
+        assert_eq!(Card::try_new(7, 1), Err(EngineError::InvalidCard));
+        assert_eq!(Card::new(3, 5).shape(), Some(Shape::Triangle));
+        assert_eq!(
+            repack_legacy(&[1, 20, 6, 8]),
+            Ok(vec![Card::new(1, 20).pack(), Card::new(6, 8).pack()])
+        );
+    }
+}
@@ -0,0 +1,224 @@
+//! Deck commitments for hidden hands.
+//!
+//! The dealer orders a full deck off-chain and commits to it with a Merkle
+//! root over one salted leaf per deck position. The VRF deal then hands out
+//! positions, not cards, so nobody can see an opponent's hand from the game
+//! account. A player plays a face-down card by opening its leaf, and the
+//! dealer reveals the secret and deck order at the end of every round so the
+//! remaining hands can be counted.
+//!
+//! Trust assumption: there is one commitment per deck, not one per hand.
+//! The VRF output is public, so a hand dealt from it on-chain could be
+//! rebuilt by anyone, and only a party that knows the deck order can keep
+//! hands private. That party is the dealer in `Config`. It sees every hand
+//! and must be trusted not to share them, which holds for the house dealer
+//! and for no one seated at the table. What it cannot do is cheat the deal:
+//! it commits before the VRF picks who gets which position, every card
+//! played or revealed is checked against its root, and a dealer that stalls
+//! instead of revealing gets the game called off.
+
+use crate::card::Card;
+use crate::deck::{full_deck, DeckHasher, DECK_SIZE};
+use crate::error::{EngineError, Result};
+
+/// Depth of the deck tree, the 54 leaves are padded to 64
+pub const DECK_TREE_DEPTH: usize = 6;
+const TREE_LEAVES: usize = 1 << DECK_TREE_DEPTH;
+
+const LEAF_TAG: u8 = 0;
+const NODE_TAG: u8 = 1;
+
+/// Salt of deck `position`, derived from the dealer's secret so the whole
+/// deck can be revealed with the secret alone
+pub fn position_salt<H: DeckHasher>(secret: &[u8; 32], position: u8) -> [u8; 32] {
+    H::hashv(&[secret, &[position]])
+}
+
+pub fn leaf_hash<H: DeckHasher>(position: u8, card: &Card, salt: &[u8; 32]) -> [u8; 32] {
+    H::hashv(&[&[LEAF_TAG, position, card.pack()], salt])
+}
+
+fn node_hash<H: DeckHasher>(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    H::hashv(&[&[NODE_TAG], left, right])
+}
+
+/// Every level of the tree, leaves first and the root last
+fn tree_levels<H: DeckHasher>(leaves: &[[u8; 32]]) -> Vec<Vec<[u8; 32]>> {
+    let mut level = leaves.to_vec();
+    level.resize(TREE_LEAVES, [0; 32]);
+
+    let mut levels = vec![level];
+    while levels[levels.len() - 1].len() > 1 {
+        let next = levels[levels.len() - 1]
+            .chunks_exact(2)
+            .map(|pair| node_hash::<H>(&pair[0], &pair[1]))
+            .collect();
+        levels.push(next);
+    }
+    levels
+}
+
+pub fn merkle_root<H: DeckHasher>(leaves: &[[u8; 32]]) -> [u8; 32] {
+    tree_levels::<H>(leaves)[DECK_TREE_DEPTH][0]
+}
+
+/// Sibling hashes from leaf `index` up to the root
+pub fn merkle_proof<H: DeckHasher>(leaves: &[[u8; 32]], index: usize) -> Vec<[u8; 32]> {
+    tree_levels::<H>(leaves)[..DECK_TREE_DEPTH]
+        .iter()
+        .enumerate()
+        .map(|(depth, level)| level[(index >> depth) ^ 1])
+        .collect()
+}
+
+pub fn verify_proof<H: DeckHasher>(
+    root: &[u8; 32],
+    leaf: [u8; 32],
+    index: usize,
+    proof: &[[u8; 32]],
+) -> bool {
+    if proof.len() != DECK_TREE_DEPTH || index >= TREE_LEAVES {
+        return false;
+    }
+    let computed = proof.iter().enumerate().fold(leaf, |node, (depth, sibling)| {
+        if (index >> depth) & 1 == 0 {
+            node_hash::<H>(&node, sibling)
+        } else {
+            node_hash::<H>(sibling, &node)
+        }
+    });
+    computed == *root
+}
+
+/// Proof that `card` sits at deck `position` of a committed deck
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Opening {
+    pub position: u8,
+    pub card: Card,
+    pub salt: [u8; 32],
+    pub proof: Vec<[u8; 32]>,
+}
+
+impl Opening {
+    pub fn verify<H: DeckHasher>(&self, root: &[u8; 32]) -> bool {
+        self.card.is_valid()
+            && (self.position as usize) < DECK_SIZE
+            && verify_proof::<H>(
+                root,
+                leaf_hash::<H>(self.position, &self.card, &self.salt),
+                self.position as usize,
+                &self.proof,
+            )
+    }
+}
+
+fn deck_leaves<H: DeckHasher>(secret: &[u8; 32], cards: &[Card]) -> Vec<[u8; 32]> {
+    cards
+        .iter()
+        .enumerate()
+        .map(|(position, card)| {
+            let position = position as u8;
+            leaf_hash::<H>(position, card, &position_salt::<H>(secret, position))
+        })
+        .collect()
+}
+
+/// Whether `cards` is a full deck, in any order
+fn is_full_deck(cards: &[Card]) -> bool {
+    let mut expected: Vec<u8> = full_deck().iter().map(Card::pack).collect();
+    let mut actual: Vec<u8> = cards.iter().map(Card::pack).collect();
+    expected.sort_unstable();
+    actual.sort_unstable();
+    expected == actual
+}
+
+/// Check a revealed deck against the root the dealer committed to
+pub fn verify_deck<H: DeckHasher>(root: &[u8; 32], secret: &[u8; 32], cards: &[Card]) -> bool {
+    is_full_deck(cards) && merkle_root::<H>(&deck_leaves::<H>(secret, cards)) == *root
+}
+
+/// The dealer's side of a committed deck, kept off-chain
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DealerDeck {
+    secret: [u8; 32],
+    cards: Vec<Card>,
+}
+
+impl DealerDeck {
+    /// `cards` must be a full deck, ordered however the dealer likes
+    pub fn new(secret: [u8; 32], cards: Vec<Card>) -> Result<Self> {
+        if !is_full_deck(&cards) {
+            return Err(EngineError::InvalidCard);
+        }
+        Ok(DealerDeck { secret, cards })
+    }
+
+    pub fn secret(&self) -> &[u8; 32] {
+        &self.secret
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    pub fn root<H: DeckHasher>(&self) -> [u8; 32] {
+        merkle_root::<H>(&deck_leaves::<H>(&self.secret, &self.cards))
+    }
+
+    /// Opening for deck `position`, handed privately to whoever holds it
+    pub fn opening<H: DeckHasher>(&self, position: u8) -> Opening {
+        let leaves = deck_leaves::<H>(&self.secret, &self.cards);
+        Opening {
+            position,
+            card: self.cards[position as usize],
+            salt: position_salt::<H>(&self.secret, position),
+            proof: merkle_proof::<H>(&leaves, position as usize),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "sha2"))]
+mod tests {
+    use super::*;
+    use crate::deck::{shuffle_cards, Sha256};
+
+    #[test]
+    fn openings_prove_their_position_only() {
+        let deck = DealerDeck::new([9; 32], shuffle_cards::<Sha256>(3)).unwrap();
+        let root = deck.root::<Sha256>();
+
+        for position in [0, 17, 53] {
+            let opening = deck.opening::<Sha256>(position);
+            assert!(opening.verify::<Sha256>(&root));
+
+            let moved = Opening {
+                position: position ^ 1,
+                ..opening.clone()
+            };
+            assert!(!moved.verify::<Sha256>(&root));
+
+            let other_card = deck.cards()[(position as usize + 1) % DECK_SIZE];
+            let forged = Opening {
+                card: other_card,
+                ..opening
+            };
+            assert!(other_card == deck.cards()[position as usize] || !forged.verify::<Sha256>(&root));
+        }
+    }
+
+    #[test]
+    fn revealed_deck_must_match_the_root() {
+        let deck = DealerDeck::new([9; 32], shuffle_cards::<Sha256>(3)).unwrap();
+        let root = deck.root::<Sha256>();
+        assert!(verify_deck::<Sha256>(&root, deck.secret(), deck.cards()));
+        assert!(!verify_deck::<Sha256>(&root, &[8; 32], deck.cards()));
+
+        let mut swapped = deck.cards().to_vec();
+        swapped.swap(0, 1);
+        assert!(swapped == deck.cards() || !verify_deck::<Sha256>(&root, deck.secret(), &swapped));
+
+        let mut short = deck.cards().to_vec();
+        short.truncate(DECK_SIZE - 1);
+        assert_eq!(DealerDeck::new([9; 32], short).err(), Some(EngineError::InvalidCard));
+    }
+}
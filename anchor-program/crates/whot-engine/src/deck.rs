@@ -0,0 +1,79 @@
+use crate::card::{pack_cards, Card, Shape};
+
+pub const CIRCLE: [u8; 12] = [1, 2, 3, 4, 5, 7, 8, 10, 11, 12, 13, 14];
+pub const TRIANGLE: [u8; 12] = [1, 2, 3, 4, 5, 7, 8, 10, 11, 12, 13, 14];
+pub const CROSS: [u8; 9] = [1, 2, 3, 5, 7, 10, 11, 13, 14];
+pub const SQUARE: [u8; 9] = [1, 2, 3, 5, 7, 10, 11, 13, 14];
+pub const STAR: [u8; 7] = [1, 2, 3, 4, 5, 7, 8];
+pub const WHOT: [u8; 5] = [20, 20, 20, 20, 20];
+
+pub const DECK_SIZE: usize = 54;
+pub const MAX_CARD_NUMBER: u8 = 14; // highest shape card, WHOT cards are 20
+
+/// Hash used to shuffle the deck. The program plugs in Solana's `hashv`
+/// syscall, off-chain code uses [`Sha256`]; both produce the same deck.
+pub trait DeckHasher {
+    fn hashv(vals: &[&[u8]]) -> [u8; 32];
+}
+
+#[cfg(feature = "sha2")]
+pub struct Sha256;
+
+#[cfg(feature = "sha2")]
+impl DeckHasher for Sha256 {
+    fn hashv(vals: &[&[u8]]) -> [u8; 32] {
+        use sha2::Digest;
+
+        let mut hasher = sha2::Sha256::new();
+        for val in vals {
+            hasher.update(val);
+        }
+        hasher.finalize().into()
+    }
+}
+
+pub fn get_card_array<const N: usize>(id: u8, cards: [u8; N]) -> [Card; N] {
+    cards.map(|card_number| Card::new(id, card_number))
+}
+
+/// The full, unshuffled 54 card deck
+pub fn full_deck() -> Vec<Card> {
+    let mut full_deck: Vec<Card> = Vec::with_capacity(DECK_SIZE);
+    for shape in Shape::ALL {
+        full_deck.extend(shape.numbers().iter().map(|number| Card::new(shape.id(), *number)));
+    }
+    full_deck
+}
+
+pub fn shuffle_cards<H: DeckHasher>(random_seed: u64) -> Vec<Card> {
+    let mut full_deck = full_deck();
+    shuffle_deck::<H>(&mut full_deck, random_seed);
+    full_deck
+}
+
+/// Commitment to the deck shuffled from `random_seed`: the hash of every
+/// card in dealing order, packed one byte per card
+pub fn deck_commitment<H: DeckHasher>(random_seed: u64) -> [u8; 32] {
+    let deck = pack_cards(&shuffle_cards::<H>(random_seed));
+    H::hashv(&[&deck])
+}
+
+pub fn shuffle_deck<H: DeckHasher>(cards: &mut [Card], random_seed: u64) {
+    // Fisher–Yates shuffle using hashv to generate pseudo-random indices
+    let mut seed_bytes = random_seed.to_le_bytes();
+    let len = cards.len();
+
+    for i in (1..len).rev() {
+        // Generate a new hash each iteration using the previous seed and index
+        let hash_bytes = H::hashv(&[&seed_bytes, &(i as u64).to_le_bytes()]);
+
+        // Convert first 8 bytes of hash to u64 for randomness
+        let rand_num = u64::from_le_bytes(hash_bytes[0..8].try_into().unwrap());
+        let j = (rand_num % (i as u64 + 1)) as usize;
+
+        cards.swap(i, j);
+
+        // Update seed to this hash for next iteration
+        seed_bytes = hash_bytes[0..8].try_into().unwrap();
+    }
+}
@@ -0,0 +1,63 @@
+use core::fmt;
+
+pub type Result<T> = core::result::Result<T, EngineError>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EngineError {
+    InvalidNoPlayers,
+    InvalidRuleSet,
+    NotYourTurn,
+    CannotPlayCard,
+    NoCallCard,
+    NoDrawPile,
+    PlayerNotFound,
+    GameNotStarted,
+    GameEnded,
+    RoundEnded,
+    CannotPenalizeYourself,
+    InvalidNeed,
+    NoPendingPenalty,
+    PenaltyPending,
+    InvalidCard,
+    CardNotInHand,
+    AwaitingReveal,
+    CallCardHidden,
+    CardNotHidden,
+    PlayerOut,
+    OutOfTime,
+    TurnNotOverdue,
+    RoundInProgress,
+}
+
+impl fmt::Display for EngineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            EngineError::InvalidNoPlayers => "Invalid number of players",
+            EngineError::InvalidRuleSet => "Invalid rule set",
+            EngineError::NotYourTurn => "Not your turn",
+            EngineError::CannotPlayCard => "Cannot play this card",
+            EngineError::NoCallCard => "No call card to validate",
+            EngineError::NoDrawPile => "No draw pile",
+            EngineError::PlayerNotFound => "Player not found",
+            EngineError::GameNotStarted => "Game not started",
+            EngineError::GameEnded => "Game ended",
+            EngineError::RoundEnded => "Round ended, waiting for the next deal",
+            EngineError::CannotPenalizeYourself => "Cannot penalize yourself",
+            EngineError::InvalidNeed => "A WHOT card must request a valid shape",
+            EngineError::NoPendingPenalty => "No pending penalty to accept",
+            EngineError::PenaltyPending => "Defend or accept the pending penalty first",
+            EngineError::InvalidCard => "Card is not part of the deck",
+            EngineError::CardNotInHand => "Card is not in your hand",
+            EngineError::AwaitingReveal => "Round over, waiting for the dealer to reveal the deck",
+            EngineError::CallCardHidden => "Call card has not been revealed yet",
+            EngineError::CardNotHidden => "No face-down card at that position",
+            EngineError::PlayerOut => "Player is out of the game",
+            EngineError::OutOfTime => "Out of time, the move can only be penalized",
+            EngineError::TurnNotOverdue => "Player on turn is not overdue yet",
+            EngineError::RoundInProgress => "Round in progress, it is already dealt",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl std::error::Error for EngineError {}
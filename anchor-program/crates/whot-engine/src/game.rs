@@ -0,0 +1,1130 @@
+use crate::card::{Card, Shape};
+use crate::deck::{shuffle_cards, shuffle_deck, DeckHasher, DECK_SIZE};
+use crate::error::{EngineError, Result};
+use crate::moves::{Action, Move};
+use crate::rules::{Effect, MAX_MOVES};
+use crate::state::GameState;
+
+#[cfg(feature = "sha2")]
+use crate::deck::Sha256;
+
+/// Deal a new round from `random_seed`
+#[cfg(feature = "sha2")]
+pub fn deal(state: GameState, random_seed: u64, now: i64) -> Result<GameState> {
+    deal_with::<Sha256>(state, random_seed, now)
+}
+
+/// Apply `mv` to `state` at unix time `now`
+#[cfg(feature = "sha2")]
+pub fn apply_move(state: GameState, mv: Move, now: i64) -> Result<GameState> {
+    apply_move_with::<Sha256>(state, mv, now)
+}
+
+pub fn deal_with<H: DeckHasher>(mut state: GameState, random_seed: u64, now: i64) -> Result<GameState> {
+    state.deal::<H>(random_seed, now)?;
+    Ok(state)
+}
+
+pub fn apply_move_with<H: DeckHasher>(mut state: GameState, mv: Move, now: i64) -> Result<GameState> {
+    state.apply::<H>(mv, now)?;
+    Ok(state)
+}
+
+impl GameState {
+    pub fn deal<H: DeckHasher>(&mut self, random_seed: u64, now: i64) -> Result<()> {
+        if self.ended {
+            return Err(EngineError::GameEnded);
+        }
+        if self.players.len() != self.no_players as usize {
+            return Err(EngineError::InvalidNoPlayers);
+        }
+        if self.started && !self.round_ended {
+            return Err(EngineError::RoundInProgress);
+        }
+
+        //get a shuffled full deck, face down when hands are hidden so
+        //the seed only decides who gets which committed position
+        let shuffled_cards = if self.rules.hidden_hands {
+            let mut positions: Vec<Card> = (0..DECK_SIZE as u8).map(Card::hidden).collect();
+            shuffle_deck::<H>(&mut positions, random_seed);
+            positions
+        } else {
+            shuffle_cards::<H>(random_seed)
+        };
+
+        let num_players = self.no_players as usize;
+        let cards_per_player = self.rules.hand_size as usize;
+        let total_to_share = num_players * cards_per_player;
+        if total_to_share >= shuffled_cards.len() {
+            return Err(EngineError::InvalidRuleSet);
+        }
+
+        //slice out the part of the deck for sharing
+        let cards_to_share = &shuffled_cards[0..total_to_share];
+        let call_card = shuffled_cards[total_to_share];
+        let draw_pile = shuffled_cards[total_to_share + 1..].to_vec();
+
+        //distribute cards to each player
+        for (i, player) in self.players.iter_mut().enumerate() {
+            let start = i * cards_per_player;
+            let end = start + cards_per_player;
+
+            player.hand = cards_to_share[start..end].to_vec();
+            player.card_count = None;
+            player.player_index = Some((i + 1) as u8);
+        }
+
+        //assign call card and draw pile, clearing the previous round's table
+        self.call_card = Some(call_card);
+        self.draw_pile = draw_pile;
+        self.discard_pile = vec![];
+        self.reshuffles = 0;
+        self.requested_shape = None;
+        self.pending_pick = 0;
+
+        //update game state, the first turn moves one seat along every round
+        self.random_seed = Some(random_seed);
+        self.round += 1;
+        self.round_ended = false;
+        if !self.started {
+            self.started = true;
+            self.started_at = Some(now);
+            // the clock runs over the whole match, not per round
+            if let Some(time_control) = self.rules.time_control {
+                for player in &mut self.players {
+                    player.time_bank = time_control.bank;
+                }
+            }
+        }
+        self.player_turn = (self.round - 1) % self.no_players + 1;
+        if self.players[self.current_index()].out {
+            self.next_turn(1);
+        }
+        self.last_move_time = Some(now);
+        Ok(())
+    }
+
+    /// Run `mv` against the state. A rejected move can leave the state half
+    /// changed, so outside the crate moves go through `apply_move`, which
+    /// only hands back the state once the move went through.
+    pub(crate) fn apply<H: DeckHasher>(&mut self, mv: Move, now: i64) -> Result<()> {
+        // counted up front, so a round the move ends knows it is the last
+        self.moves = self.moves.saturating_add(1);
+        if let Action::ResumeClock = mv.action {
+            // clock restarts never settle the match, the next move does
+            return self.resume_clock(now);
+        }
+        self.apply_action::<H>(mv, now)?;
+        if self.moves >= MAX_MOVES {
+            // the move limit settles the round by count and ends the match
+            self.handle_market_finish(now);
+        }
+        Ok(())
+    }
+
+    fn apply_action<H: DeckHasher>(&mut self, mv: Move, now: i64) -> Result<()> {
+        if !self.started {
+            return Err(EngineError::GameNotStarted);
+        }
+        if self.ended {
+            return Err(EngineError::GameEnded);
+        }
+        if self.round_ended {
+            return Err(EngineError::RoundEnded);
+        }
+        if self.awaiting_reveal {
+            return Err(EngineError::AwaitingReveal);
+        }
+        match self.player(mv.seat) {
+            None => return Err(EngineError::PlayerNotFound),
+            Some(player) if player.out => return Err(EngineError::PlayerOut),
+            Some(_) => {}
+        }
+        if self.call_card.is_some_and(|card| card.is_hidden()) {
+            return Err(EngineError::CallCardHidden);
+        }
+
+        if let Action::Penalize = mv.action {
+            if mv.seat == self.player_turn {
+                return Err(EngineError::CannotPenalizeYourself);
+            }
+            return self.handle_penalize_opponent::<H>(now);
+        }
+        if let Action::Resign = mv.action {
+            self.handle_resign(mv.seat, now);
+            return Ok(());
+        }
+        if let Action::Timeout = mv.action {
+            if mv.seat != self.player_turn {
+                return Err(EngineError::NotYourTurn);
+            }
+            return self.handle_penalize_opponent::<H>(now);
+        }
+
+        if mv.seat != self.player_turn {
+            return Err(EngineError::NotYourTurn);
+        }
+        self.run_clock(now)?;
+        let index = self.current_index();
+        self.players[index].timeouts = 0;
+        match mv.action {
+            Action::Play { card, need } => {
+                self.validate_play(&card, need)?;
+                self.last_move_time = Some(now);
+                self.handle_call_card::<H>(now)
+            }
+            Action::Draw => {
+                self.last_move_time = Some(now);
+                self.handle_draw_from_pile::<H>(now)
+            }
+            Action::AcceptPenalty => {
+                self.last_move_time = Some(now);
+                self.handle_accept_penalty::<H>(now)
+            }
+            Action::Penalize | Action::Resign | Action::Timeout | Action::ResumeClock => {
+                unreachable!()
+            }
+        }
+    }
+
+    /// Restart the turn clock at `now`, the seat on turn gets its whole
+    /// allowance back from then on. Never moves the clock back.
+    fn resume_clock(&mut self, now: i64) -> Result<()> {
+        if !self.started {
+            return Err(EngineError::GameNotStarted);
+        }
+        if self.round_over() {
+            return Err(EngineError::RoundEnded);
+        }
+        self.last_move_time = Some(self.last_move_time.map_or(now, |last| last.max(now)));
+        Ok(())
+    }
+
+    /// Turn the face-down card at deck `position` face up, wherever it is.
+    /// The caller checks `card` against the dealer's commitment first.
+    pub fn reveal(&mut self, position: u8, card: Card) -> Result<()> {
+        let hidden = Card::hidden(position);
+        let slot = self
+            .call_card
+            .iter_mut()
+            .chain(self.draw_pile.iter_mut())
+            .chain(self.players.iter_mut().flat_map(|player| player.hand.iter_mut()))
+            .find(|slot| **slot == hidden)
+            .ok_or(EngineError::CardNotHidden)?;
+        *slot = card;
+        Ok(())
+    }
+
+    /// Turn every face-down card up from the dealer's revealed deck order,
+    /// then settle the round if it was waiting for the reveal.
+    /// The caller checks `cards` against the dealer's commitment first.
+    pub fn reveal_deck(&mut self, cards: &[Card], now: i64) -> Result<()> {
+        if cards.len() != DECK_SIZE {
+            return Err(EngineError::InvalidCard);
+        }
+        let slots = self
+            .call_card
+            .iter_mut()
+            .chain(self.draw_pile.iter_mut())
+            .chain(self.players.iter_mut().flat_map(|player| player.hand.iter_mut()));
+        for slot in slots {
+            if let Some(position) = slot.hidden_position() {
+                *slot = cards[position as usize];
+            }
+        }
+
+        if self.awaiting_reveal {
+            self.awaiting_reveal = false;
+            match self
+                .players
+                .iter()
+                .position(|player| !player.out && player.hand.is_empty())
+            {
+                Some(index) => {
+                    self.player_turn = (index + 1) as u8;
+                    self.settle_checkup(now);
+                }
+                None => self.settle_market_finish(now),
+            }
+        }
+        Ok(())
+    }
+
+    fn current_index(&self) -> usize {
+        (self.player_turn - 1) as usize
+    }
+
+    /// Seconds the seat on turn has for its move before it can be
+    /// penalized: its time bank, or `wait_time` without a clock or once
+    /// the bank has run out
+    pub fn move_allowance(&self) -> i64 {
+        match (self.rules.time_control, self.current_player()) {
+            (Some(_), Some(player)) if player.time_bank > 0 => player.time_bank,
+            _ => self.wait_time,
+        }
+    }
+
+    /// When the seat on turn becomes overdue, `None` outside of play
+    pub fn turn_deadline(&self) -> Option<i64> {
+        if !self.started || self.round_over() {
+            return None;
+        }
+        let last_move = self.last_move_time.or(self.started_at)?;
+        Some(last_move + self.move_allowance())
+    }
+
+    /// Whether the seat on turn has used up its allowance and can be penalized
+    pub fn is_overdue(&self, now: i64) -> bool {
+        // Use last_move_time or fallback to game start time
+        let last_move = self.last_move_time.unwrap_or(self.started_at.unwrap_or(now));
+        now - last_move > self.move_allowance()
+    }
+
+    /// Charge the seat on turn for the time its move took. A flagged seat
+    /// stays on `wait_time`, it earns no increment.
+    fn run_clock(&mut self, now: i64) -> Result<()> {
+        let Some(time_control) = self.rules.time_control else {
+            return Ok(());
+        };
+        let elapsed = now - self.last_move_time.or(self.started_at).unwrap_or(now);
+        if elapsed > self.move_allowance() {
+            return Err(EngineError::OutOfTime);
+        }
+        let index = self.current_index();
+        let player = &mut self.players[index];
+        if player.time_bank > 0 {
+            player.time_bank = (player.time_bank - elapsed).max(0) + time_control.increment;
+        }
+        Ok(())
+    }
+
+    fn handle_call_card<H: DeckHasher>(&mut self, now: i64) -> Result<()> {
+        let call_card = self.call_card.ok_or(EngineError::NoCallCard)?;
+        match self.rules.effect_of(&call_card) {
+            Some(Effect::HoldOn) => self.handle_hold_on::<H>(now),
+            Some(Effect::PickTwo) => self.handle_pick::<H>(2, now),
+            Some(Effect::PickThree) => self.handle_pick::<H>(3, now),
+            Some(Effect::Suspension) => self.handle_suspension::<H>(now),
+            Some(Effect::GeneralMarket) => self.handle_general_market::<H>(now),
+            Some(Effect::Need) => self.handle_need::<H>(now),
+            None => self.handle_neutral_play::<H>(now),
+        }
+    }
+
+    fn validate_play(&mut self, card: &Card, need: Option<u8>) -> Result<()> {
+        let call_card = self.call_card.ok_or(EngineError::NoCallCard)?;
+        if !card.is_valid() {
+            return Err(EngineError::InvalidCard);
+        }
+        let index = self.current_index();
+        let position = self.players[index]
+            .hand
+            .iter()
+            .position(|c| c == card)
+            .ok_or(EngineError::CardNotInHand)?;
+
+        // a WHOT card must declare the shape the next player needs,
+        // any other card must not
+        let is_whot = card.is_whot();
+        let need_valid = match need {
+            Some(shape) => is_whot && Shape::from_id(shape).is_some_and(Shape::is_needable),
+            None => !is_whot,
+        };
+        if !need_valid {
+            return Err(EngineError::InvalidNeed);
+        }
+
+        if !self.can_play(card) {
+            return Err(EngineError::CannotPlayCard);
+        }
+
+        // remove exactly one copy of the card from current player's hand
+        self.players[index].hand.remove(position);
+
+        // discard the old call card, then update call card and
+        // the shape the next player needs
+        self.discard_pile.push(call_card);
+        self.call_card = Some(*card);
+        self.requested_shape = need;
+        Ok(())
+    }
+
+    /// Whether `card` can be played on the current call card
+    pub fn can_play(&self, card: &Card) -> bool {
+        let Some(call_card) = self.call_card else {
+            return false;
+        };
+        if card.is_hidden() {
+            return false;
+        }
+
+        // while a pick penalty is pending only the same pick card can defend,
+        // a WHOT card can be played on anything, otherwise the card must
+        // follow the requested shape or match the call card
+        if self.pending_pick > 0 {
+            card.card_number == call_card.card_number
+        } else if card.is_whot() {
+            true
+        } else if let Some(shape) = self.requested_shape {
+            card.id == shape
+        } else {
+            call_card.is_whot() || card.id == call_card.id || card.card_number == call_card.card_number
+        }
+    }
+
+    fn check_winner<H: DeckHasher>(&mut self, now: i64) -> Result<()> {
+        if self.ended {
+            return Err(EngineError::GameEnded);
+        }
+        if self.round_ended {
+            return Err(EngineError::RoundEnded);
+        }
+
+        // first check if player hand is empty
+        if self.players[self.current_index()].hand.is_empty() {
+            self.handle_checkup(now);
+            return Ok(());
+        }
+
+        // second check if market has finished, reshuffling the discard
+        // pile into a new market when the rules allow it
+        if self.draw_pile.is_empty() {
+            if self.rules.reshuffle_market && !self.discard_pile.is_empty() {
+                self.handle_reshuffle::<H>()?;
+            } else {
+                self.handle_market_finish(now);
+            }
+        }
+        Ok(())
+    }
+
+    /// Pass the turn `step` seats along, skipping seats that are out
+    fn next_turn(&mut self, step: u8) {
+        for _ in 0..step {
+            loop {
+                self.player_turn = self.player_turn % self.no_players + 1;
+                if !self.players[self.current_index()].out {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Check for a winner, then pass the turn `step` seats along unless
+    /// the round is over
+    fn finish_move<H: DeckHasher>(&mut self, step: u8, now: i64) -> Result<()> {
+        self.check_winner::<H>(now)?;
+        if self.round_over() {
+            return Ok(());
+        }
+        self.next_turn(step);
+        Ok(())
+    }
+
+    fn handle_draw_from_pile<H: DeckHasher>(&mut self, now: i64) -> Result<()> {
+        if self.pending_pick > 0 {
+            return Err(EngineError::PenaltyPending);
+        }
+        // remove top card from draw pile and add to current player's hand
+        let card = self.draw_card::<H>()?.ok_or(EngineError::NoDrawPile)?;
+        let index = self.current_index();
+        self.players[index].hand.push(card);
+        self.finish_move::<H>(1, now)
+    }
+
+    fn handle_neutral_play<H: DeckHasher>(&mut self, now: i64) -> Result<()> {
+        self.finish_move::<H>(1, now)
+    }
+
+    fn handle_pick<H: DeckHasher>(&mut self, count: u8, now: i64) -> Result<()> {
+        // pass the penalty on to the next player, who can defend or accept it
+        self.pending_pick = self.pending_pick.saturating_add(count);
+        self.finish_move::<H>(1, now)?;
+        if !self.round_over() && !self.rules.defend_picks {
+            return self.handle_accept_penalty::<H>(now);
+        }
+        Ok(())
+    }
+
+    fn handle_accept_penalty<H: DeckHasher>(&mut self, now: i64) -> Result<()> {
+        if self.pending_pick == 0 {
+            return Err(EngineError::NoPendingPenalty);
+        }
+
+        // current player draws the accumulated penalty, or what is left of the market
+        let index = self.current_index();
+        for _ in 0..self.pending_pick {
+            let Some(card) = self.draw_card::<H>()? else {
+                break;
+            };
+            self.players[index].hand.push(card);
+        }
+        self.pending_pick = 0;
+
+        self.finish_move::<H>(1, now)
+    }
+
+    fn handle_suspension<H: DeckHasher>(&mut self, now: i64) -> Result<()> {
+        self.finish_move::<H>(2, now)
+    }
+
+    fn handle_hold_on<H: DeckHasher>(&mut self, now: i64) -> Result<()> {
+        // current player goes again
+        self.check_winner::<H>(now)
+    }
+
+    fn handle_general_market<H: DeckHasher>(&mut self, now: i64) -> Result<()> {
+        // every other player draws one card while the market lasts,
+        // then the current player goes again
+        let total_players = self.no_players as usize;
+        let current = self.current_index();
+        for offset in 1..total_players {
+            let player = &mut self.players[(current + offset) % total_players];
+            if player.out {
+                continue;
+            }
+            let Some(card) = self.draw_pile.pop() else {
+                break;
+            };
+            player.hand.push(card);
+        }
+
+        self.check_winner::<H>(now)
+    }
+
+    fn handle_need<H: DeckHasher>(&mut self, now: i64) -> Result<()> {
+        self.finish_move::<H>(1, now)
+    }
+
+    /// Top card of the market, once the discard pile was reshuffled into a
+    /// new one if it ran dry and the rules allow it
+    fn draw_card<H: DeckHasher>(&mut self) -> Result<Option<Card>> {
+        if self.draw_pile.is_empty() && self.rules.reshuffle_market && !self.discard_pile.is_empty() {
+            self.handle_reshuffle::<H>()?;
+        }
+        Ok(self.draw_pile.pop())
+    }
+
+    fn handle_reshuffle<H: DeckHasher>(&mut self) -> Result<()> {
+        let random_seed = self.random_seed.ok_or(EngineError::GameNotStarted)?;
+
+        // every reshuffle uses a fresh seed derived from the game's random seed
+        self.reshuffles = self.reshuffles.saturating_add(1);
+        let mut cards = std::mem::take(&mut self.discard_pile);
+        shuffle_deck::<H>(&mut cards, random_seed.wrapping_add(self.reshuffles as u64));
+        self.draw_pile = cards;
+        Ok(())
+    }
+
+    fn handle_count_cards(&mut self) {
+        let star_double = self.rules.star_double;
+        for player in &mut self.players {
+            let total_count = player
+                .hand
+                .iter()
+                .fold(0u8, |total, card| total.saturating_add(card.value(star_double)));
+            player.card_count = Some(total_count);
+        }
+    }
+
+    /// Hidden hands cannot be counted until the dealer reveals the deck,
+    /// so the round waits for `reveal_deck` to settle it
+    fn await_reveal(&mut self) -> bool {
+        if !self.rules.hidden_hands {
+            return false;
+        }
+        self.awaiting_reveal = true;
+        self.player_turn = 0;
+        true
+    }
+
+    fn handle_checkup(&mut self, now: i64) {
+        if self.round_over() || self.await_reveal() {
+            return;
+        }
+        self.settle_checkup(now);
+    }
+
+    fn settle_checkup(&mut self, now: i64) {
+        self.handle_count_cards();
+        let round_winner = Some(self.player_turn);
+        let round_team = self.team_of(self.player_turn);
+        self.handle_round_end(round_winner, round_team, now);
+    }
+
+    fn handle_market_finish(&mut self, now: i64) {
+        if self.round_over() || self.await_reveal() {
+            return;
+        }
+        self.settle_market_finish(now);
+    }
+
+    fn settle_market_finish(&mut self, now: i64) {
+        self.handle_count_cards();
+
+        // Lowest card count wins the round, partners' counts are summed in
+        // team mode, no winner on a tie
+        if self.rules.team_mode {
+            let round_team = self.lowest_team(|count, _| count);
+            self.handle_round_end(None, round_team, now);
+        } else {
+            let round_winner = self.lowest_seat(|count, _| count);
+            self.handle_round_end(round_winner, None, now);
+        }
+    }
+
+    fn handle_round_end(&mut self, round_winner: Option<u8>, round_team: Option<u8>, now: i64) {
+        // add this round's counts to every player's match score
+        for player in &mut self.players {
+            player.score = player
+                .score
+                .saturating_add(player.card_count.unwrap_or(0) as u16);
+        }
+        self.player_turn = 0;
+
+        if self.rules.rounds == 1 {
+            // a single hand is decided by the hand itself
+            self.winner = round_winner;
+            self.winning_team = round_team;
+        } else {
+            let target_reached = self
+                .rules
+                .target_score
+                .is_some_and(|target| self.players.iter().any(|p| p.score >= target));
+
+            if self.round < self.rules.rounds && !target_reached && self.moves < MAX_MOVES {
+                // wait for the next deal
+                self.round_ended = true;
+                return;
+            }
+
+            // Lowest match score wins the match, no winner on a tie
+            if self.rules.team_mode {
+                self.winning_team = self.lowest_team(|_, score| score);
+            } else {
+                self.winner = self.lowest_seat(|_, score| score);
+            }
+        }
+
+        self.ended = true;
+        self.ended_at = Some(now);
+    }
+
+    /// Seat with the lowest `(card_count, score)` metric, `None` on a tie
+    fn lowest_seat(&self, metric: impl Fn(u16, u16) -> u16) -> Option<u8> {
+        let scores: Vec<(u8, u16)> = self
+            .players
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| !p.out)
+            .map(|(i, p)| ((i + 1) as u8, metric(p.card_count.unwrap_or(0) as u16, p.score)))
+            .collect();
+
+        // Get the minimum score across all players
+        let min_score = scores.iter().map(|(_, score)| *score).min()?;
+
+        // Collect all players who share that minimum score
+        let mut lowest_seats = scores.iter().filter(|(_, score)| *score == min_score);
+        let (seat, _) = lowest_seats.next()?;
+
+        // Check if there's more than one player with the same lowest score
+        if lowest_seats.next().is_some() {
+            None
+        } else {
+            Some(*seat)
+        }
+    }
+
+    /// Team with the lowest summed `(card_count, score)` metric, `None` on a tie
+    fn lowest_team(&self, metric: impl Fn(u16, u16) -> u16) -> Option<u8> {
+        let mut totals = [0u16; 2];
+        for (i, player) in self.players.iter().enumerate() {
+            if let Some(team) = self.team_of((i + 1) as u8) {
+                let value = metric(player.card_count.unwrap_or(0) as u16, player.score);
+                totals[team as usize] = totals[team as usize].saturating_add(value);
+            }
+        }
+
+        match totals[0].cmp(&totals[1]) {
+            std::cmp::Ordering::Less => Some(0),
+            std::cmp::Ordering::Greater => Some(1),
+            std::cmp::Ordering::Equal => None, // No winner due to tie
+        }
+    }
+
+    /// Take `seat` out of the game. Its hand stays where it is and still
+    /// counts against its team, but it gets no more turns. The game ends
+    /// once a single player, or a single team, is left.
+    fn handle_resign(&mut self, seat: u8, now: i64) {
+        self.players[(seat - 1) as usize].out = true;
+
+        let active: Vec<u8> = self.active_seats().collect();
+        if self.rules.team_mode {
+            let first = self.team_of(active[0]);
+            if active.iter().all(|seat| self.team_of(*seat) == first) {
+                self.winning_team = first;
+                self.end_by_resignation(now);
+                return;
+            }
+        } else if let [last] = active[..] {
+            self.winner = Some(last);
+            self.end_by_resignation(now);
+            return;
+        }
+
+        if seat == self.player_turn {
+            // a pick penalty aimed at the resigning seat leaves with it
+            self.pending_pick = 0;
+            self.next_turn(1);
+            self.last_move_time = Some(now);
+        }
+    }
+
+    fn end_by_resignation(&mut self, now: i64) {
+        self.player_turn = 0;
+        self.pending_pick = 0;
+        self.ended = true;
+        self.ended_at = Some(now);
+    }
+
+    fn handle_penalize_opponent<H: DeckHasher>(&mut self, now: i64) -> Result<()> {
+        // Only penalize if player exceeded wait_time, or its time bank
+        if !self.is_overdue(now) {
+            return Err(EngineError::TurnNotOverdue);
+        }
+        self.last_move_time = Some(now);
+
+        let seat = self.player_turn;
+        let index = self.current_index();
+        let player = &mut self.players[index];
+        player.timeouts = player.timeouts.saturating_add(1);
+        if self.rules.max_timeouts.is_some_and(|max| player.timeouts >= max) {
+            self.handle_resign(seat, now);
+            return Ok(());
+        }
+
+        // a flagged seat plays on `wait_time` per move, or forfeits
+        if let Some(time_control) = self.rules.time_control {
+            self.players[index].time_bank = 0;
+            if time_control.forfeit_on_flag {
+                self.handle_resign(seat, now);
+                return Ok(());
+            }
+        }
+
+        // An overdue player facing a pick penalty takes it instead
+        if self.pending_pick > 0 {
+            return self.handle_accept_penalty::<H>(now);
+        }
+
+        // Draw one card from draw pile to penalize current player
+        let Some(card) = self.draw_card::<H>()? else {
+            self.handle_market_finish(now);
+            return Ok(());
+        };
+        self.players[index].hand.push(card);
+
+        self.finish_move::<H>(1, now)
+    }
+}
+
+#[cfg(all(test, feature = "sha2"))]
+mod tests {
+    use super::*;
+    use crate::rules::{RuleSet, TimeControl};
+    use crate::state::PlayerState;
+
+    fn started_game(no_players: u8, rules: RuleSet) -> GameState {
+        let mut state = GameState::new(no_players, 60, rules);
+        state.players = vec![PlayerState::default(); no_players as usize];
+        deal(state, 42, 1_000).unwrap()
+    }
+
+    fn set_table(state: &mut GameState, call_card: Card, hands: &[&[Card]]) {
+        state.call_card = Some(call_card);
+        for (player, hand) in state.players.iter_mut().zip(hands) {
+            player.hand = hand.to_vec();
+        }
+    }
+
+    fn play(seat: u8, id: u8, card_number: u8, need: Option<u8>) -> Move {
+        Move::new(seat, Action::Play { card: Card::new(id, card_number), need })
+    }
+
+    #[test]
+    fn deal_shares_the_whole_deck() {
+        let state = started_game(3, RuleSet::classic());
+        let in_hands: usize = state.players.iter().map(|p| p.hand.len()).sum();
+
+        assert_eq!(in_hands, 6);
+        assert_eq!(in_hands + 1 + state.draw_pile.len(), crate::deck::DECK_SIZE);
+        assert_eq!(state.player_turn, 1);
+        assert_eq!(state.round, 1);
+    }
+
+    #[test]
+    fn whot_card_requests_a_shape() {
+        let mut state = started_game(2, RuleSet::classic());
+        set_table(
+            &mut state,
+            Card::new(2, 7),
+            &[&[Card::new(1, 20), Card::new(3, 4)], &[Card::new(4, 3), Card::new(5, 3)]],
+        );
+
+        assert_eq!(
+            apply_move(state.clone(), play(1, 1, 20, None), 1_010),
+            Err(EngineError::InvalidNeed)
+        );
+        let state = apply_move(state, play(1, 1, 20, Some(5)), 1_010).unwrap();
+        assert_eq!(state.requested_shape, Some(5));
+        assert_eq!(state.player_turn, 2);
+
+        assert_eq!(
+            apply_move(state.clone(), play(2, 4, 3, None), 1_020),
+            Err(EngineError::CannotPlayCard)
+        );
+        let state = apply_move(state, play(2, 5, 3, None), 1_020).unwrap();
+        assert_eq!(state.requested_shape, None);
+    }
+
+    #[test]
+    fn pick_two_can_be_defended_and_stacked() {
+        let mut state = started_game(3, RuleSet::classic());
+        set_table(
+            &mut state,
+            Card::new(2, 7),
+            &[
+                &[Card::new(2, 2), Card::new(3, 4)],
+                &[Card::new(4, 2), Card::new(5, 3)],
+                &[Card::new(5, 7), Card::new(6, 1)],
+            ],
+        );
+
+        let state = apply_move(state, play(1, 2, 2, None), 1_010).unwrap();
+        assert_eq!(state.pending_pick, 2);
+        assert_eq!(
+            apply_move(state.clone(), Move::new(2, Action::Draw), 1_020),
+            Err(EngineError::PenaltyPending)
+        );
+
+        let state = apply_move(state, play(2, 4, 2, None), 1_020).unwrap();
+        assert_eq!(state.pending_pick, 4);
+        assert_eq!(state.player_turn, 3);
+
+        let state = apply_move(state, Move::new(3, Action::AcceptPenalty), 1_030).unwrap();
+        assert_eq!(state.pending_pick, 0);
+        assert_eq!(state.players[2].hand.len(), 6);
+        assert_eq!(state.player_turn, 1);
+    }
+
+    #[test]
+    fn checkup_ends_a_single_hand() {
+        let mut state = started_game(2, RuleSet::classic());
+        set_table(
+            &mut state,
+            Card::new(2, 7),
+            &[&[Card::new(2, 10)], &[Card::new(6, 4), Card::new(3, 3)]],
+        );
+
+        let state = apply_move(state, play(1, 2, 10, None), 1_010).unwrap();
+        assert!(state.ended);
+        assert_eq!(state.winner, Some(1));
+        assert_eq!(state.players[1].card_count, Some(11));
+        assert_eq!(
+            apply_move(state, Move::new(2, Action::Draw), 1_020),
+            Err(EngineError::GameEnded)
+        );
+    }
+
+    #[test]
+    fn rounds_accumulate_scores() {
+        let rules = RuleSet { rounds: 2, ..RuleSet::classic() };
+        let mut state = started_game(2, rules);
+        set_table(
+            &mut state,
+            Card::new(2, 7),
+            &[&[Card::new(2, 10)], &[Card::new(3, 3)]],
+        );
+        assert_eq!(deal(state.clone(), 7, 1_005), Err(EngineError::RoundInProgress));
+
+        let state = apply_move(state, play(1, 2, 10, None), 1_010).unwrap();
+        assert!(state.round_ended && !state.ended);
+        assert_eq!(state.players[1].score, 3);
+
+        let mut state = deal(state, 7, 1_100).unwrap();
+        assert_eq!(state.round, 2);
+        assert_eq!(state.player_turn, 2);
+        set_table(
+            &mut state,
+            Card::new(4, 5),
+            &[&[Card::new(6, 2), Card::new(3, 1)], &[Card::new(4, 7)]],
+        );
+
+        let state = apply_move(state, play(2, 4, 7, None), 1_110).unwrap();
+        assert!(state.ended);
+        assert_eq!(state.players[0].score, 5);
+        assert_eq!(state.winner, Some(2));
+    }
+
+    #[test]
+    fn the_move_limit_settles_the_match() {
+        let rules = RuleSet { rounds: 3, ..RuleSet::classic() };
+        let mut state = started_game(2, rules);
+        set_table(
+            &mut state,
+            Card::new(2, 7),
+            &[&[Card::new(4, 13), Card::new(3, 4)], &[Card::new(5, 3)]],
+        );
+        state.moves = MAX_MOVES - 1;
+
+        let state = apply_move(state, Move::new(1, Action::Draw), 1_010).unwrap();
+        assert!(state.ended && !state.round_ended);
+        // seat 1 holds 13 + 4 and the card it drew, seat 2 holds 3
+        assert_eq!(state.winner, Some(2));
+    }
+
+    #[test]
+    fn team_mode_sums_partner_counts() {
+        let rules = RuleSet { team_mode: true, ..RuleSet::classic() };
+        let mut state = started_game(4, rules);
+        set_table(
+            &mut state,
+            Card::new(2, 7),
+            &[
+                &[Card::new(2, 1)],
+                &[Card::new(2, 2), Card::new(3, 3)],
+                &[Card::new(4, 13)],
+                &[Card::new(5, 1)],
+            ],
+        );
+        state.draw_pile = vec![Card::new(3, 11)];
+
+        let state = apply_move(state, Move::new(1, Action::Draw), 1_010).unwrap();
+        assert!(state.ended);
+        // team 0 holds 1 + 11 + 13, team 1 holds 2 + 3 + 1
+        assert_eq!(state.winning_team, Some(1));
+        assert_eq!(state.winner, None);
+    }
+
+    #[test]
+    fn penalize_only_when_overdue() {
+        let state = started_game(2, RuleSet::classic());
+        let hand_size = state.players[0].hand.len();
+
+        assert_eq!(
+            apply_move(state.clone(), Move::new(2, Action::Penalize), 1_030),
+            Err(EngineError::TurnNotOverdue)
+        );
+        assert_eq!(
+            apply_move(state.clone(), Move::new(1, Action::Penalize), 1_100),
+            Err(EngineError::CannotPenalizeYourself)
+        );
+
+        let state = apply_move(state, Move::new(2, Action::Penalize), 1_100).unwrap();
+        assert_eq!(state.players[0].hand.len(), hand_size + 1);
+        assert_eq!(state.player_turn, 2);
+    }
+
+    #[test]
+    fn penalties_reshuffle_a_dry_market() {
+        let mut rules = RuleSet::classic();
+        rules.reshuffle_market = true;
+        let mut state = started_game(2, rules);
+        set_table(
+            &mut state,
+            Card::new(2, 7),
+            &[&[Card::new(2, 2), Card::new(3, 4)], &[Card::new(4, 3), Card::new(5, 3)]],
+        );
+        state.discard_pile = std::mem::take(&mut state.draw_pile);
+        state.draw_pile = vec![Card::new(1, 1)];
+
+        // the pick two takes the last market card and the reshuffled discard pile
+        let mut dealt = state.clone();
+        dealt.rules.defend_picks = false;
+        let dealt = apply_move(dealt, play(1, 2, 2, None), 1_010).unwrap();
+        assert_eq!(dealt.players[1].hand.len(), 4);
+        assert_eq!(dealt.reshuffles, 1);
+        assert!(!dealt.ended);
+
+        // an overdue seat is penalized from the reshuffled discard pile
+        state.draw_pile.clear();
+        let state = apply_move(state, Move::new(2, Action::Penalize), 1_100).unwrap();
+        assert_eq!(state.players[0].hand.len(), 3);
+        assert_eq!(state.reshuffles, 1);
+        assert!(!state.ended);
+    }
+
+    #[test]
+    fn resigned_seats_are_skipped() {
+        let mut state = started_game(3, RuleSet::classic());
+        set_table(
+            &mut state,
+            Card::new(2, 7),
+            &[
+                &[Card::new(2, 3), Card::new(3, 4)],
+                &[Card::new(4, 2), Card::new(5, 3)],
+                &[Card::new(2, 14), Card::new(6, 1)],
+            ],
+        );
+
+        let state = apply_move(state, Move::new(2, Action::Resign), 1_005).unwrap();
+        assert!(state.players[1].out && !state.ended);
+        assert_eq!(state.player_turn, 1);
+        assert_eq!(
+            apply_move(state.clone(), Move::new(2, Action::Penalize), 1_100),
+            Err(EngineError::PlayerOut)
+        );
+
+        // the turn skips seat 2, and so does general market
+        let state = apply_move(state, play(1, 2, 3, None), 1_010).unwrap();
+        assert_eq!(state.player_turn, 3);
+        let state = apply_move(state, play(3, 2, 14, None), 1_020).unwrap();
+        assert_eq!(state.players[0].hand.len(), 2);
+        assert_eq!(state.players[1].hand.len(), 2);
+        assert_eq!(state.player_turn, 3);
+
+        // the last seat standing wins
+        let state = apply_move(state, Move::new(3, Action::Resign), 1_030).unwrap();
+        assert!(state.ended);
+        assert_eq!(state.winner, Some(1));
+        assert_eq!(state.player_turn, 0);
+    }
+
+    #[test]
+    fn time_banks_run_down_and_flag() {
+        let clock = TimeControl { bank: 100, increment: 5, forfeit_on_flag: false };
+        let rules = RuleSet { time_control: Some(clock), ..RuleSet::classic() };
+        let mut state = started_game(2, rules);
+        assert!(state.players.iter().all(|p| p.time_bank == 100));
+        set_table(
+            &mut state,
+            Card::new(2, 7),
+            &[
+                &[Card::new(2, 3), Card::new(3, 4)],
+                &[Card::new(4, 2), Card::new(5, 3)],
+            ],
+        );
+
+        // 30 seconds come off the bank and the increment goes back on
+        let state = apply_move(state, Move::new(1, Action::Draw), 1_030).unwrap();
+        assert_eq!(state.players[0].time_bank, 75);
+        assert_eq!(state.turn_deadline(), Some(1_130));
+
+        // seat 2 is overdue past its bank, not past `wait_time`
+        assert_eq!(
+            apply_move(state.clone(), Move::new(1, Action::Penalize), 1_120),
+            Err(EngineError::TurnNotOverdue)
+        );
+        assert_eq!(
+            apply_move(state.clone(), Move::new(2, Action::Draw), 1_131),
+            Err(EngineError::OutOfTime)
+        );
+        let flagged = apply_move(state.clone(), Move::new(1, Action::Penalize), 1_131).unwrap();
+        assert_eq!(flagged.players[1].hand.len(), 3);
+        assert_eq!(flagged.players[1].time_bank, 0);
+        let flagged = apply_move(flagged, Move::new(1, Action::Draw), 1_140).unwrap();
+        let flagged = apply_move(flagged, Move::new(2, Action::Draw), 1_150).unwrap();
+        assert_eq!(flagged.players[1].time_bank, 0);
+
+        // or the flag forfeits the seat
+        let mut forfeit = state;
+        forfeit.rules.time_control = Some(TimeControl { forfeit_on_flag: true, ..clock });
+        let forfeit = apply_move(forfeit, Move::new(1, Action::Penalize), 1_131).unwrap();
+        assert!(forfeit.players[1].out && forfeit.ended);
+        assert_eq!(forfeit.winner, Some(1));
+    }
+
+    #[test]
+    fn timeouts_need_an_overdue_seat() {
+        let state = started_game(2, RuleSet::classic());
+        let seat = state.player_turn;
+        assert_eq!(
+            apply_move(state.clone(), Move::new(seat, Action::Timeout), 1_060),
+            Err(EngineError::TurnNotOverdue)
+        );
+        assert_eq!(
+            apply_move(state.clone(), Move::new(seat % 2 + 1, Action::Timeout), 1_061),
+            Err(EngineError::NotYourTurn)
+        );
+
+        let other = seat % 2 + 1;
+        let timed_out = apply_move(state.clone(), Move::new(seat, Action::Timeout), 1_061).unwrap();
+        let penalized = apply_move(state, Move::new(other, Action::Penalize), 1_061).unwrap();
+        assert_eq!(timed_out, penalized);
+    }
+
+    #[test]
+    fn repeated_timeouts_forfeit_the_seat() {
+        let rules = RuleSet { max_timeouts: Some(2), ..RuleSet::classic() };
+        let state = started_game(3, rules);
+        let in_play = |state: &GameState| {
+            let in_hands: usize = state.players.iter().map(|p| p.hand.len()).sum();
+            in_hands + state.draw_pile.len() + state.discard_pile.len() + 1
+        };
+
+        // seat 1 times out, then moves, so the count starts over
+        let state = apply_move(state, Move::new(2, Action::Penalize), 1_061).unwrap();
+        assert_eq!(state.players[0].timeouts, 1);
+        let state = apply_move(state, Move::new(2, Action::Draw), 1_070).unwrap();
+        let state = apply_move(state, Move::new(3, Action::Draw), 1_080).unwrap();
+        let state = apply_move(state, Move::new(1, Action::Draw), 1_090).unwrap();
+        assert_eq!(state.players[0].timeouts, 0);
+
+        // seat 2 times out two turns in a row
+        let state = apply_move(state, Move::new(1, Action::Penalize), 1_151).unwrap();
+        let state = apply_move(state, Move::new(3, Action::Draw), 1_160).unwrap();
+        let state = apply_move(state, Move::new(1, Action::Draw), 1_170).unwrap();
+        let state = apply_move(state, Move::new(1, Action::Penalize), 1_231).unwrap();
+        assert!(state.players[1].out && !state.players[1].hand.is_empty());
+        assert!(!state.ended);
+        assert_eq!(state.player_turn, 3);
+        assert_eq!(in_play(&state), crate::deck::DECK_SIZE);
+    }
+
+    #[test]
+    fn forfeited_hands_count_for_their_team() {
+        let rules = RuleSet { team_mode: true, max_timeouts: Some(1), ..RuleSet::classic() };
+        let mut state = started_game(4, rules);
+        set_table(
+            &mut state,
+            Card::new(2, 7),
+            &[
+                &[Card::new(4, 13), Card::new(2, 12)],
+                &[Card::new(2, 2), Card::new(3, 3)],
+                &[Card::new(4, 1)],
+                &[Card::new(5, 1)],
+            ],
+        );
+        state.draw_pile = vec![Card::new(3, 11)];
+
+        let state = apply_move(state, Move::new(2, Action::Penalize), 1_061).unwrap();
+        assert!(state.players[0].out && !state.ended);
+        let state = apply_move(state, Move::new(2, Action::Draw), 1_070).unwrap();
+        assert!(state.ended);
+        // team 0 still holds the forfeited 13 + 12 next to 1, team 1 holds 2 + 3 + 11 + 1
+        assert_eq!(state.winning_team, Some(1));
+    }
+
+    #[test]
+    fn hidden_hands_are_dealt_face_down() {
+        let rules = RuleSet { hidden_hands: true, ..RuleSet::classic() };
+        let mut state = started_game(2, rules);
+        assert!(state.players.iter().flat_map(|p| &p.hand).all(Card::is_hidden));
+        assert!(state.draw_pile.iter().all(Card::is_hidden));
+
+        // nothing moves until the call card is turned up
+        assert_eq!(
+            apply_move(state.clone(), Move::new(1, Action::Draw), 1_010),
+            Err(EngineError::CallCardHidden)
+        );
+        let position = state.call_card.unwrap().hidden_position().unwrap();
+        state.reveal(position, Card::new(2, 7)).unwrap();
+        assert_eq!(state.reveal(position, Card::new(2, 7)), Err(EngineError::CardNotHidden));
+
+        // a face-down card has to be revealed before it can be played
+        let hidden = state.players[0].hand[0];
+        assert!(state.legal_moves(1, 1_010).cards.is_empty());
+        state.reveal(hidden.hidden_position().unwrap(), Card::new(2, 4)).unwrap();
+        assert_eq!(state.legal_moves(1, 1_010).cards, vec![Card::new(2, 4)]);
+    }
+}
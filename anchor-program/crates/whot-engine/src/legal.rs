@@ -0,0 +1,98 @@
+use crate::card::Card;
+use crate::state::GameState;
+
+/// Everything a seat is allowed to do right now
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LegalMoves {
+    /// Playable cards in the seat's hand, each listed once. A WHOT card
+    /// still needs a requested shape when played.
+    pub cards: Vec<Card>,
+    pub can_draw: bool,
+    pub can_accept_penalty: bool,
+    /// The seat ran out of time on its clock, it can only resign or be
+    /// penalized, so nothing else is listed
+    pub out_of_time: bool,
+}
+
+impl GameState {
+    /// Legal moves for `seat` at `now`, empty when it is not the seat's
+    /// turn. Face-down cards of a hidden hand are never listed, they can
+    /// only be judged once revealed, so `cards` is empty for a hand that is
+    /// still all face down.
+    pub fn legal_moves(&self, seat: u8, now: i64) -> LegalMoves {
+        let mut legal_moves = LegalMoves::default();
+        if !self.started || self.round_over() || seat != self.player_turn {
+            return legal_moves;
+        }
+        let Some(player) = self.player(seat) else {
+            return legal_moves;
+        };
+        // past its allowance on a clock every move fails with `OutOfTime`
+        if self.rules.time_control.is_some() && self.is_overdue(now) {
+            legal_moves.out_of_time = true;
+            return legal_moves;
+        }
+
+        for card in &player.hand {
+            if self.can_play(card) && !legal_moves.cards.contains(card) {
+                legal_moves.cards.push(*card);
+            }
+        }
+        legal_moves.can_draw = self.pending_pick == 0 && !self.draw_pile.is_empty();
+        legal_moves.can_accept_penalty = self.pending_pick > 0;
+        legal_moves
+    }
+}
+
+#[cfg(all(test, feature = "sha2"))]
+mod tests {
+    use super::*;
+    use crate::rules::{RuleSet, TimeControl};
+    use crate::state::PlayerState;
+
+    #[test]
+    fn lists_playable_cards_for_the_seat_on_turn() {
+        let mut state = GameState::new(2, 60, RuleSet::classic());
+        state.players = vec![PlayerState::default(); 2];
+        let mut state = crate::deal(state, 9, 1_000).unwrap();
+        state.call_card = Some(Card::new(2, 2));
+        state.players[0].hand = vec![
+            Card::new(1, 20),
+            Card::new(1, 20),
+            Card::new(3, 2),
+            Card::new(2, 8),
+            Card::new(4, 7),
+        ];
+
+        let legal_moves = state.legal_moves(1, 1_010);
+        assert_eq!(
+            legal_moves.cards,
+            vec![Card::new(1, 20), Card::new(3, 2), Card::new(2, 8)]
+        );
+        assert!(legal_moves.can_draw);
+        assert!(!legal_moves.can_accept_penalty);
+        assert_eq!(state.legal_moves(2, 1_010), LegalMoves::default());
+
+        // only another 2 defends a pending pick two
+        state.pending_pick = 2;
+        let legal_moves = state.legal_moves(1, 1_010);
+        assert_eq!(legal_moves.cards, vec![Card::new(3, 2)]);
+        assert!(!legal_moves.can_draw);
+        assert!(legal_moves.can_accept_penalty);
+    }
+
+    #[test]
+    fn nothing_is_listed_for_a_seat_out_of_time() {
+        let mut rules = RuleSet::classic();
+        rules.time_control = Some(TimeControl { bank: 100, increment: 0, forfeit_on_flag: false });
+        let mut state = GameState::new(2, 60, rules);
+        state.players = vec![PlayerState::default(); 2];
+        let state = crate::deal(state, 9, 1_000).unwrap();
+
+        assert!(!state.legal_moves(1, 1_100).out_of_time);
+        assert_eq!(
+            state.legal_moves(1, 1_101),
+            LegalMoves { out_of_time: true, ..LegalMoves::default() }
+        );
+    }
+}
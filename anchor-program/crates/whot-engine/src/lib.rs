@@ -0,0 +1,30 @@
+//! Whot rules engine.
+//!
+//! Pure, clock-free implementation of the rules run by the Degen Decks
+//! program. The on-chain program wraps these types, so anything built on
+//! this crate (backend, bots, clients) plays by exactly the same rules.
+
+pub mod card;
+pub mod commitment;
+pub mod deck;
+pub mod error;
+pub mod game;
+pub mod legal;
+pub mod moves;
+pub mod replay;
+pub mod rules;
+pub mod state;
+
+pub use card::*;
+pub use commitment::*;
+pub use deck::*;
+pub use error::*;
+pub use legal::*;
+pub use moves::*;
+pub use replay::*;
+pub use rules::*;
+pub use state::*;
+
+#[cfg(feature = "sha2")]
+pub use game::{apply_move, deal};
+pub use game::{apply_move_with, deal_with};
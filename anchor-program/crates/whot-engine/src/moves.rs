@@ -0,0 +1,35 @@
+use crate::card::Card;
+
+/// What a seat does on its move
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    /// Play a card, a WHOT card declares the shape the next player needs
+    Play { card: Card, need: Option<u8> },
+    /// Draw one card from the market
+    Draw,
+    /// Draw the pending pick penalty instead of defending it
+    AcceptPenalty,
+    /// Penalize the player on turn for running out of time
+    Penalize,
+    /// Leave the game, forfeiting the stake. The seat is skipped from then on.
+    Resign,
+    /// Anyone times out the seat on turn, recorded against that seat. Same
+    /// penalty as `Penalize`, and likewise rejected unless the seat is overdue.
+    Timeout,
+    /// Not a move of the seat: its turn clock restarts at the move's time,
+    /// after moves were paused or the call card was turned up late
+    ResumeClock,
+}
+
+/// A move made by a seat (1 based, same as `PlayerState::player_index`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Move {
+    pub seat: u8,
+    pub action: Action,
+}
+
+impl Move {
+    pub fn new(seat: u8, action: Action) -> Self {
+        Move { seat, action }
+    }
+}
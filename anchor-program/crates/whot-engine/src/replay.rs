@@ -0,0 +1,328 @@
+use std::fmt;
+
+use crate::card::Card;
+use crate::deck::DeckHasher;
+use crate::error::EngineError;
+use crate::moves::Move;
+use crate::state::{GameState, PlayerState};
+
+#[cfg(feature = "sha2")]
+use crate::deck::Sha256;
+
+/// A deal as recorded by the program, one per round. Hidden hands rounds
+/// also carry the deck order the dealer revealed when the round ended.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DealRecord {
+    pub random_seed: u64,
+    pub timestamp: i64,
+    pub deck: Vec<Card>,
+    pub revealed_at: Option<i64>,
+}
+
+/// A move as recorded by the program
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MoveRecord {
+    pub mv: Move,
+    pub timestamp: i64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReplayError {
+    /// Deal `round` (0 based) was rejected
+    Deal { round: usize, error: EngineError },
+    /// Move `index` was rejected
+    Move { index: usize, error: EngineError },
+    /// Move `index` came after a round ended but no deal is left
+    MissingDeal { index: usize },
+    /// More deals were recorded than rounds were played
+    UnusedDeals,
+    /// Hidden hands round `round` (0 based) has not been revealed yet
+    MissingReveal { round: usize },
+    /// The replayed state differs from the stored state
+    Mismatch,
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayError::Deal { round, error } => write!(f, "deal {round} rejected: {error}"),
+            ReplayError::Move { index, error } => write!(f, "move {index} rejected: {error}"),
+            ReplayError::MissingDeal { index } => write!(f, "no deal left before move {index}"),
+            ReplayError::UnusedDeals => f.write_str("more deals than rounds played"),
+            ReplayError::MissingReveal { round } => write!(f, "deal {round} was not revealed"),
+            ReplayError::Mismatch => f.write_str("replay does not match the stored state"),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+#[cfg(feature = "sha2")]
+pub fn replay(
+    lobby: GameState,
+    deals: &[DealRecord],
+    moves: &[MoveRecord],
+) -> Result<GameState, ReplayError> {
+    replay_with::<Sha256>(lobby, deals, moves)
+}
+
+#[cfg(feature = "sha2")]
+pub fn verify_replay(
+    stored: &GameState,
+    deals: &[DealRecord],
+    moves: &[MoveRecord],
+) -> Result<(), ReplayError> {
+    verify_replay_with::<Sha256>(stored, deals, moves)
+}
+
+/// Re-run a game from its lobby: every round is dealt from its recorded
+/// random seed, the same way `consume_randomness` deals it, and the moves
+/// are applied in order. Hidden hands are turned face up from the revealed
+/// deck as soon as they are dealt, and the round is settled with the same
+/// reveal once it ends.
+pub fn replay_with<H: DeckHasher>(
+    mut state: GameState,
+    deals: &[DealRecord],
+    moves: &[MoveRecord],
+) -> Result<GameState, ReplayError> {
+    let mut deals = deals.iter().enumerate();
+    let mut current: Option<(usize, &DealRecord)> = None;
+    let mut index = 0;
+
+    loop {
+        if state.awaiting_reveal {
+            if let Some((round, deal)) = current {
+                let revealed_at = deal.revealed_at.ok_or(ReplayError::MissingReveal { round })?;
+                state
+                    .reveal_deck(&deal.deck, revealed_at)
+                    .map_err(|error| ReplayError::Deal { round, error })?;
+            }
+        }
+
+        // deal the first round and every round after one ended
+        if !state.started || state.round_ended {
+            match deals.next() {
+                Some((round, deal)) => {
+                    state
+                        .deal::<H>(deal.random_seed, deal.timestamp)
+                        .map_err(|error| ReplayError::Deal { round, error })?;
+                    if state.rules.hidden_hands {
+                        if deal.revealed_at.is_none() {
+                            return Err(ReplayError::MissingReveal { round });
+                        }
+                        state
+                            .reveal_deck(&deal.deck, deal.timestamp)
+                            .map_err(|error| ReplayError::Deal { round, error })?;
+                    }
+                    current = Some((round, deal));
+                    continue;
+                }
+                None if index < moves.len() => return Err(ReplayError::MissingDeal { index }),
+                None => break,
+            }
+        }
+
+        let Some(record) = moves.get(index) else {
+            break;
+        };
+        state
+            .apply::<H>(record.mv, record.timestamp)
+            .map_err(|error| ReplayError::Move { index, error })?;
+        index += 1;
+    }
+
+    if deals.next().is_some() {
+        return Err(ReplayError::UnusedDeals);
+    }
+    Ok(state)
+}
+
+/// Replay the logs from an empty lobby shaped like `stored` and check the
+/// result reproduces `stored` exactly
+pub fn verify_replay_with<H: DeckHasher>(
+    stored: &GameState,
+    deals: &[DealRecord],
+    moves: &[MoveRecord],
+) -> Result<(), ReplayError> {
+    let mut lobby = GameState::new(stored.no_players, stored.wait_time, stored.rules);
+    lobby.players = vec![PlayerState::default(); stored.players.len()];
+
+    let replayed = replay_with::<H>(lobby, deals, moves)?;
+    if replayed != *stored {
+        return Err(ReplayError::Mismatch);
+    }
+    Ok(())
+}
+
+#[cfg(all(test, feature = "sha2"))]
+mod tests {
+    use super::*;
+    use crate::moves::Action;
+    use crate::rules::{RuleSet, TimeControl};
+
+    fn lobby(rules: RuleSet) -> GameState {
+        let mut state = GameState::new(2, 60, rules);
+        state.players = vec![PlayerState::default(); 2];
+        state
+    }
+
+    /// Play a game with the first legal move every turn, recording it.
+    /// Hidden hands are played the way the program sees them: the call card
+    /// and every played card are revealed one at a time, and the whole deck
+    /// only once the round is over.
+    fn record_game(rules: RuleSet) -> (GameState, Vec<DealRecord>, Vec<MoveRecord>) {
+        let mut state = lobby(rules);
+        let mut deals: Vec<DealRecord> = vec![];
+        let mut moves = vec![];
+        let mut now = 1_000;
+
+        while !state.ended && moves.len() < 1_000 {
+            if state.awaiting_reveal {
+                now += 5;
+                let deal = deals.last_mut().unwrap();
+                state.reveal_deck(&deal.deck, now).unwrap();
+                deal.revealed_at = Some(now);
+                continue;
+            }
+            if state.round == 0 || state.round_ended {
+                let mut deal = DealRecord {
+                    random_seed: 7 + state.round as u64,
+                    timestamp: now,
+                    ..DealRecord::default()
+                };
+                state.deal::<Sha256>(deal.random_seed, deal.timestamp).unwrap();
+                if rules.hidden_hands {
+                    deal.deck = crate::deck::shuffle_cards::<Sha256>(100 + state.round as u64);
+                    let position = state.call_card.unwrap().hidden_position().unwrap();
+                    state.reveal(position, deal.deck[position as usize]).unwrap();
+                }
+                deals.push(deal);
+                continue;
+            }
+
+            now += 10;
+            let seat = state.player_turn;
+            let deck = &deals.last().unwrap().deck;
+            let face_up = |card: &Card| match card.hidden_position() {
+                Some(position) => deck[position as usize],
+                None => *card,
+            };
+            let mut view = state.clone();
+            for card in &mut view.players[seat as usize - 1].hand {
+                *card = face_up(card);
+            }
+
+            let legal = view.legal_moves(seat, now);
+            let action = match legal.cards.first() {
+                Some(card) => {
+                    if let Some(hidden) = state.players[seat as usize - 1]
+                        .hand
+                        .iter()
+                        .find(|hidden| hidden.is_hidden() && face_up(hidden) == *card)
+                    {
+                        state.reveal(hidden.hidden_position().unwrap(), *card).unwrap();
+                    }
+                    Action::Play {
+                        card: *card,
+                        need: card.is_whot().then_some(2),
+                    }
+                }
+                None if legal.can_accept_penalty => Action::AcceptPenalty,
+                None => Action::Draw,
+            };
+            let record = MoveRecord {
+                mv: Move::new(seat, action),
+                timestamp: now,
+            };
+            state.apply::<Sha256>(record.mv, record.timestamp).unwrap();
+            moves.push(record);
+        }
+        (state, deals, moves)
+    }
+
+    #[test]
+    fn replay_reproduces_the_stored_state() {
+        for rules in [
+            RuleSet::classic(),
+            RuleSet {
+                rounds: 3,
+                ..RuleSet::casual()
+            },
+            RuleSet {
+                rounds: 2,
+                hidden_hands: true,
+                ..RuleSet::classic()
+            },
+        ] {
+            let (stored, deals, moves) = record_game(rules);
+            assert_eq!(verify_replay(&stored, &deals, &moves), Ok(()));
+        }
+    }
+
+    #[test]
+    fn clock_restarts_are_replayed() {
+        let clock = TimeControl { bank: 100, increment: 0, forfeit_on_flag: false };
+        let rules = RuleSet { time_control: Some(clock), ..RuleSet::classic() };
+        let deals = vec![DealRecord {
+            random_seed: 7,
+            timestamp: 1_000,
+            ..DealRecord::default()
+        }];
+        // seat 1 draws, then moves are paused for 500 seconds before seat 2 draws
+        let moves = vec![
+            MoveRecord { mv: Move::new(1, Action::Draw), timestamp: 1_010 },
+            MoveRecord { mv: Move::new(2, Action::ResumeClock), timestamp: 1_510 },
+            MoveRecord { mv: Move::new(2, Action::Draw), timestamp: 1_540 },
+        ];
+        let stored = replay(lobby(rules), &deals, &moves).unwrap();
+        assert_eq!(stored.players[1].time_bank, 70);
+        assert_eq!(verify_replay(&stored, &deals, &moves), Ok(()));
+
+        // without the restart the pause runs seat 2 out of its bank
+        let unpaused = [moves[0], moves[2]];
+        assert_eq!(
+            replay(lobby(rules), &deals, &unpaused),
+            Err(ReplayError::Move { index: 1, error: EngineError::OutOfTime })
+        );
+    }
+
+    #[test]
+    fn hidden_rounds_need_the_reveal() {
+        let rules = RuleSet {
+            hidden_hands: true,
+            ..RuleSet::classic()
+        };
+        let (stored, mut deals, moves) = record_game(rules);
+        assert!(stored.ended);
+
+        deals[0].revealed_at = None;
+        assert_eq!(
+            verify_replay(&stored, &deals, &moves),
+            Err(ReplayError::MissingReveal { round: 0 })
+        );
+    }
+
+    #[test]
+    fn tampered_logs_are_detected() {
+        let (stored, deals, mut moves) = record_game(RuleSet::classic());
+
+        let mut other_seed = deals.clone();
+        other_seed[0].random_seed += 1;
+        assert!(verify_replay(&stored, &other_seed, &moves).is_err());
+
+        let last = moves.pop().unwrap();
+        assert_eq!(
+            verify_replay(&stored, &deals, &moves),
+            Err(ReplayError::Mismatch)
+        );
+
+        moves.push(MoveRecord {
+            mv: Move::new(last.mv.seat % 2 + 1, last.mv.action),
+            ..last
+        });
+        assert!(matches!(
+            verify_replay(&stored, &deals, &moves),
+            Err(ReplayError::Move { .. })
+        ));
+    }
+}
@@ -0,0 +1,175 @@
+use crate::card::{Card, WHOT_NUMBER};
+use crate::deck::{DECK_SIZE, MAX_CARD_NUMBER};
+
+pub const NO_SHARED_CARDS: u8 = 2;
+pub const TEAM_NO_PLAYERS: u8 = 4;
+pub const MAX_ROUNDS: u8 = 10;
+/// Moves in a match, clock restarts included, before it is settled by
+/// count, so its log always fits
+pub const MAX_MOVES: u16 = 636;
+
+/// What a played card does on top of becoming the call card
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Effect {
+    HoldOn,
+    PickTwo,
+    PickThree,
+    Suspension,
+    GeneralMarket,
+    Need,
+}
+
+/// Fischer clock. Every seat starts the match with `bank` seconds, the time
+/// a move takes comes out of the bank and `increment` is added back after it.
+/// A seat whose bank ran out plays on `wait_time` per move for the rest of
+/// the match.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimeControl {
+    pub bank: i64,
+    pub increment: i64,
+    pub forfeit_on_flag: bool, // an empty bank forfeits the seat instead of drawing a card
+}
+
+/// Rules a game is played with.
+/// A special card number set to `None` makes that number play as a neutral card.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RuleSet {
+    pub hand_size: u8,
+    pub hold_on: Option<u8>,
+    pub pick_two: Option<u8>,
+    pub pick_three: Option<u8>,
+    pub suspension: Option<u8>,
+    pub general_market: Option<u8>,
+    pub star_double: bool,             // star cards count double when hands are counted
+    pub defend_picks: bool,            // pick penalties can be defended and stacked
+    pub reshuffle_market: bool,        // discard pile becomes the market when it runs out
+    pub rounds: u8,                    // rounds in the match, 1 plays a single hand
+    pub target_score: Option<u16>,     // match ends early once a player reaches this score
+    pub team_mode: bool,               // 2v2 with partners sitting opposite, needs 4 players
+    pub hidden_hands: bool,            // hands are dealt face down against a dealer commitment
+    pub time_control: Option<TimeControl>, // per seat clock, `wait_time` per move without one
+    pub max_timeouts: Option<u8>,      // a seat timed out this many turns in a row forfeits
+}
+
+impl RuleSet {
+    /// Classic Nigerian Whot
+    pub fn classic() -> Self {
+        RuleSet {
+            hand_size: NO_SHARED_CARDS,
+            hold_on: Some(1),
+            pick_two: Some(2),
+            pick_three: Some(5),
+            suspension: Some(8),
+            general_market: Some(14),
+            star_double: true,
+            defend_picks: true,
+            reshuffle_market: false,
+            rounds: 1,
+            target_score: None,
+            team_mode: false,
+            hidden_hands: false,
+            time_control: None,
+            max_timeouts: None,
+        }
+    }
+
+    /// Tournament Whot, pick penalties cannot be defended
+    pub fn tournament() -> Self {
+        RuleSet {
+            defend_picks: false,
+            ..Self::classic()
+        }
+    }
+
+    /// Casual Whot, no suspension, every card counts at face value and
+    /// the market never runs out
+    pub fn casual() -> Self {
+        RuleSet {
+            suspension: None,
+            star_double: false,
+            reshuffle_market: true,
+            ..Self::classic()
+        }
+    }
+
+    pub fn is_valid(&self, no_players: u8) -> bool {
+        // every player gets a hand and one card is left for the call card
+        let dealt = no_players as usize * self.hand_size as usize + 1;
+        if self.hand_size == 0 || dealt > DECK_SIZE {
+            return false;
+        }
+        if self.rounds == 0
+            || self.rounds > MAX_ROUNDS
+            || self.target_score == Some(0)
+            || self.max_timeouts == Some(0)
+        {
+            return false;
+        }
+        if self.team_mode && no_players != TEAM_NO_PLAYERS {
+            return false;
+        }
+        // a reshuffled market is dealt from a public seed, so every card
+        // drawn from it would be known to everyone
+        if self.hidden_hands && self.reshuffle_market {
+            return false;
+        }
+        if self
+            .time_control
+            .is_some_and(|clock| clock.bank <= 0 || clock.increment < 0)
+        {
+            return false;
+        }
+
+        let specials: Vec<u8> = [
+            self.hold_on,
+            self.pick_two,
+            self.pick_three,
+            self.suspension,
+            self.general_market,
+        ]
+        .iter()
+        .flatten()
+        .copied()
+        .collect();
+
+        // special numbers must exist in the deck and must not overlap
+        specials.iter().enumerate().all(|(i, number)| {
+            (1..=MAX_CARD_NUMBER).contains(number) && !specials[..i].contains(number)
+        })
+    }
+
+    /// Effect of playing `card` under these rules, `None` for a neutral card
+    pub fn effect_of(&self, card: &Card) -> Option<Effect> {
+        let card_number = Some(card.card_number);
+        if card_number == self.hold_on {
+            Some(Effect::HoldOn)
+        } else if card_number == self.pick_two {
+            Some(Effect::PickTwo)
+        } else if card_number == self.pick_three {
+            Some(Effect::PickThree)
+        } else if card_number == self.suspension {
+            Some(Effect::Suspension)
+        } else if card_number == self.general_market {
+            Some(Effect::GeneralMarket)
+        } else if card.card_number == WHOT_NUMBER {
+            Some(Effect::Need)
+        } else {
+            None
+        }
+    }
+
+    /// Team of a seat in team mode, partners sit opposite so seats 1 and 3
+    /// are team 0 and seats 2 and 4 are team 1
+    pub fn team_of(&self, seat: u8) -> Option<u8> {
+        if !self.team_mode || seat == 0 {
+            return None;
+        }
+        Some((seat - 1) % 2)
+    }
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self::classic()
+    }
+}
@@ -0,0 +1,98 @@
+use crate::card::Card;
+use crate::rules::RuleSet;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PlayerState {
+    pub hand: Vec<Card>,
+    pub card_count: Option<u8>,
+    pub player_index: Option<u8>,
+    pub score: u16, // card counts summed over every round of the match
+    pub out: bool,  // resigned, the seat is skipped and cannot win
+    pub time_bank: i64, // seconds left on the seat's clock, see `TimeControl`
+    pub timeouts: u8,   // turns in a row the seat was penalized for running out of time
+}
+
+/// Everything the rules need to know about a game. Seats are 1 based and
+/// match the position of the player in `players`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GameState {
+    pub no_players: u8,
+    pub player_turn: u8,
+    pub players: Vec<PlayerState>,
+    pub winner: Option<u8>,
+    pub winning_team: Option<u8>,
+    pub call_card: Option<Card>,
+    pub requested_shape: Option<u8>,
+    pub pending_pick: u8,
+    pub draw_pile: Vec<Card>,
+    pub discard_pile: Vec<Card>,
+    pub reshuffles: u8,
+    pub wait_time: i64,
+    pub rules: RuleSet,
+    pub random_seed: Option<u64>,
+    pub started: bool,
+    pub ended: bool,
+    pub round: u8,
+    pub round_ended: bool,
+    pub awaiting_reveal: bool, // hidden hands round is over, waiting for the dealer's reveal
+    pub started_at: Option<i64>,
+    pub ended_at: Option<i64>,
+    pub last_move_time: Option<i64>,
+    pub moves: u16, // moves played over the match, see `MAX_MOVES`
+}
+
+impl GameState {
+    /// A lobby waiting for `no_players` players to be seated
+    pub fn new(no_players: u8, wait_time: i64, rules: RuleSet) -> Self {
+        GameState {
+            no_players,
+            player_turn: 0,
+            players: vec![],
+            winner: None,
+            winning_team: None,
+            call_card: None,
+            requested_shape: None,
+            pending_pick: 0,
+            draw_pile: vec![],
+            discard_pile: vec![],
+            reshuffles: 0,
+            wait_time,
+            rules,
+            random_seed: None,
+            started: false,
+            ended: false,
+            round: 0,
+            round_ended: false,
+            awaiting_reveal: false,
+            started_at: None,
+            ended_at: None,
+            last_move_time: None,
+            moves: 0,
+        }
+    }
+
+    pub fn round_over(&self) -> bool {
+        self.ended || self.round_ended || self.awaiting_reveal
+    }
+
+    pub fn player(&self, seat: u8) -> Option<&PlayerState> {
+        self.players.get((seat as usize).checked_sub(1)?)
+    }
+
+    pub fn current_player(&self) -> Option<&PlayerState> {
+        self.player(self.player_turn)
+    }
+
+    pub fn team_of(&self, seat: u8) -> Option<u8> {
+        self.rules.team_of(seat)
+    }
+
+    /// Seats still in the game
+    pub fn active_seats(&self) -> impl Iterator<Item = u8> + '_ {
+        self.players
+            .iter()
+            .enumerate()
+            .filter(|(_, player)| !player.out)
+            .map(|(i, _)| (i + 1) as u8)
+    }
+}
@@ -0,0 +1,140 @@
+use whot_engine::{
+    apply_move, deal, Action, Card, EngineError, GameState, Move, PlayerState, RuleSet, Shape,
+    DECK_SIZE,
+};
+
+fn cards_in_play(state: &GameState) -> usize {
+    let in_hands: usize = state.players.iter().map(|p| p.hand.len()).sum();
+    in_hands + state.draw_pile.len() + state.discard_pile.len() + state.call_card.iter().count()
+}
+
+fn new_game(no_players: u8, rules: RuleSet, random_seed: u64) -> GameState {
+    let mut state = GameState::new(no_players, 60, rules);
+    state.players = vec![PlayerState::default(); no_players as usize];
+    deal(state, random_seed, 0).unwrap()
+}
+
+/// Plays the first legal card, asking for the shape the seat holds most of,
+/// otherwise accepts a pending penalty or draws. Every fifth turn the seat
+/// stalls and gets penalized by the next seat instead.
+fn next_move(state: &GameState, turn: usize, now: i64) -> Move {
+    let seat = state.player_turn;
+    if turn % 5 == 4 {
+        return Move::new(seat % state.no_players + 1, Action::Penalize);
+    }
+
+    let legal_moves = state.legal_moves(seat, now);
+    if let Some(card) = legal_moves.cards.first() {
+        let need = card.is_whot().then(|| {
+            let hand = &state.player(seat).unwrap().hand;
+            Shape::NEEDABLE
+                .into_iter()
+                .max_by_key(|shape| hand.iter().filter(|c| c.shape() == Some(*shape)).count())
+                .unwrap()
+                .id()
+        });
+        return Move::new(seat, Action::Play { card: *card, need });
+    }
+    if legal_moves.can_accept_penalty {
+        return Move::new(seat, Action::AcceptPenalty);
+    }
+    Move::new(seat, Action::Draw)
+}
+
+fn play_out(rules: RuleSet, no_players: u8, random_seed: u64) {
+    let mut state = new_game(no_players, rules, random_seed);
+    let mut now = 0;
+
+    for turn in 0..2_000 {
+        if state.ended {
+            break;
+        }
+        if state.round_ended {
+            state = deal(state, random_seed.wrapping_add(turn as u64), now).unwrap();
+            assert_eq!(cards_in_play(&state), DECK_SIZE);
+            continue;
+        }
+
+        now += if turn % 5 == 4 { 61 } else { 10 };
+        let mv = next_move(&state, turn, now);
+        state = apply_move(state, mv, now).unwrap_or_else(|error| {
+            panic!("seed {random_seed}: {mv:?} failed with {error}")
+        });
+        assert_eq!(
+            cards_in_play(&state),
+            DECK_SIZE,
+            "seed {random_seed}: cards lost or created by {mv:?}"
+        );
+    }
+}
+
+#[test]
+fn classic_games_keep_every_card() {
+    for random_seed in 0..50 {
+        play_out(RuleSet::classic(), 2 + (random_seed % 4) as u8, random_seed);
+    }
+}
+
+#[test]
+fn variant_games_keep_every_card() {
+    let hand_of_five = RuleSet {
+        hand_size: 5,
+        ..RuleSet::classic()
+    };
+    let match_play = RuleSet {
+        rounds: 3,
+        reshuffle_market: true,
+        ..RuleSet::tournament()
+    };
+    let teams = RuleSet {
+        team_mode: true,
+        ..RuleSet::casual()
+    };
+
+    for random_seed in 0..30 {
+        play_out(hand_of_five, 3, random_seed);
+        play_out(RuleSet::casual(), 5, random_seed);
+        play_out(match_play, 2, random_seed);
+        play_out(teams, 4, random_seed);
+    }
+}
+
+#[test]
+fn cards_outside_the_hand_are_rejected() {
+    let state = new_game(2, RuleSet::classic(), 3);
+    let hand = state.players[0].hand.clone();
+    let play = |card: Card| Move::new(1, Action::Play { card, need: None });
+
+    // cards that do not exist in the deck
+    for card in [Card::new(0, 0), Card::new(4, 4), Card::new(6, 14), Card::new(7, 1)] {
+        assert_eq!(
+            apply_move(state.clone(), play(card), 10),
+            Err(EngineError::InvalidCard)
+        );
+    }
+
+    // a real card the player does not hold
+    let not_in_hand = state.draw_pile[0];
+    assert_eq!(
+        apply_move(state.clone(), play(not_in_hand), 10),
+        Err(EngineError::CardNotInHand)
+    );
+    assert_eq!(state.players[0].hand, hand);
+}
+
+#[test]
+fn playing_a_duplicate_removes_one_copy() {
+    let mut state = new_game(2, RuleSet::classic(), 3);
+    state.call_card = Some(Card::new(2, 7));
+    state.players[0].hand = vec![Card::new(1, 20), Card::new(1, 20), Card::new(3, 4)];
+    let whot = Move::new(
+        1,
+        Action::Play {
+            card: Card::new(1, 20),
+            need: Some(3),
+        },
+    );
+
+    let state = apply_move(state, whot, 10).unwrap();
+    assert_eq!(state.players[0].hand, vec![Card::new(1, 20), Card::new(3, 4)]);
+}
@@ -2,20 +2,25 @@
 pub const CONFIG_SEED: &str = "CONFIG";
 pub const PROFILE_SEED: &str = "PROFILE";
 pub const GAME_SEED: &str = "GAME";
+pub const MOVE_LOG_SEED: &str = "MOVE_LOG";
+pub const MINT_CONFIG_SEED: &str = "MINT_CONFIG";
 
 // Config Constants
 pub const MAX_MINTS_LENGTH: u8 = 10;
+pub const MAX_STAKE_TIERS: u8 = 8;
 // Game Constants
 pub const MIN_WAIT_TIME: i64 = 30; // 30 seconds
 pub const MAX_WAIT_TIME: i64 = 120; // 2 minutes
 pub const MIN_NO_PLAYERS: u8 = 2;
 pub const MAX_NO_PLAYERS: u8 = 5;
-pub const NO_SHARED_CARDS: u8 = 2;
+pub const MIN_LOBBY_TTL: i64 = 300; // 5 minutes
+pub const MAX_LOBBY_TTL: i64 = 604_800; // 7 days
+pub const MAX_KEEPERS: u8 = 4; // keepers owed a reward per game, later ones crank for free
+pub const DEALER_TIMEOUT: i64 = 600; // 10 minutes for the dealer to commit or reveal before anyone can call the game off
+pub const CLOSE_GRACE_PERIOD: i64 = 259_200; // 3 days before anyone but the creator can close a game
+// Move Log Constants
+pub const MAX_LOGGED_DEALS: u8 = 10; // one per round, see `whot_engine::MAX_ROUNDS`
+// keeps the log under the 10KiB init limit, with room for the clock
+// restarts logged ahead of the move that settles the match
+pub const MAX_LOGGED_MOVES: u16 = whot_engine::MAX_MOVES + 4;
 
-// CARDS
-pub const CIRCLE: [u8; 12] = [1, 2, 3, 4, 5, 7, 8, 10, 11, 12, 13, 14];
-pub const TRIANGLE: [u8; 12] = [1, 2, 3, 4, 5, 7, 8, 10, 11, 12, 13, 14];
-pub const CROSS: [u8; 9] = [1, 2, 3, 5, 7, 10, 11, 13, 14];
-pub const SQUARE: [u8; 9] = [1, 2, 3, 5, 7, 10, 11, 13, 14];
-pub const STAR: [u8; 7] = [1, 2, 3, 4, 5, 7, 8];
-pub const WHOT: [u8; 5] = [20, 20, 20, 20, 20];
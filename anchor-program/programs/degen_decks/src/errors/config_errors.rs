@@ -7,5 +7,17 @@ pub enum ConfigErrors{
     #[msg("You Are Not Unauthorized")]
     NotAuthorized,
     #[msg("Invalid mints range")]
-    InvalidMintsRange
+    InvalidMintsRange,
+    #[msg("Mint is already allowed")]
+    MintAlreadyAllowed,
+    #[msg("Mint is not allowed")]
+    MintNotAllowed,
+    #[msg("No admin handover is pending for this signer")]
+    NoPendingAdmin,
+    #[msg("Invalid stake range")]
+    InvalidStakeRange,
+    #[msg("Config is already in the current layout")]
+    AlreadyMigrated,
+    #[msg("Account is not a config")]
+    InvalidConfig
 }
\ No newline at end of file
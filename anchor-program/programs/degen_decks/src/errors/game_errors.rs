@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use whot_engine::EngineError;
 
 #[error_code]
 pub enum GameErrors{
@@ -37,5 +38,107 @@ pub enum GameErrors{
     #[msg("Already claimed")]
     AlreadyClaimed,
     #[msg("Invalid authority")]
-    InvalidAuthority
-}
\ No newline at end of file
+    InvalidAuthority,
+    #[msg("A WHOT card must request a valid shape")]
+    InvalidNeed,
+    #[msg("No pending penalty to accept")]
+    NoPendingPenalty,
+    #[msg("Defend or accept the pending penalty first")]
+    PenaltyPending,
+    #[msg("Invalid rule set")]
+    InvalidRuleSet,
+    #[msg("Round ended, waiting for the next deal")]
+    RoundEnded,
+    #[msg("Round not ended")]
+    RoundNotEnded,
+    #[msg("Invalid card")]
+    InvalidCard,
+    #[msg("Card not in hand")]
+    CardNotInHand,
+    #[msg("Move log is full")]
+    MoveLogFull,
+    #[msg("Round over, waiting for the dealer to reveal the deck")]
+    AwaitingReveal,
+    #[msg("Call card has not been revealed yet")]
+    CallCardHidden,
+    #[msg("No face-down card at that position")]
+    CardNotHidden,
+    #[msg("Dealer has not committed to a deck")]
+    DeckNotCommitted,
+    #[msg("Dealer already committed to a deck")]
+    DeckAlreadyCommitted,
+    #[msg("Card does not match the committed deck")]
+    InvalidDeckProof,
+    #[msg("Hidden hand games are dealt by the dealer")]
+    DealerDeals,
+    #[msg("Waiting for players to join")]
+    WaitingForPlayers,
+    #[msg("Lobby must stay open between 5 minutes and 7 days")]
+    InvalidLobbySettings,
+    #[msg("Lobby expired")]
+    LobbyExpired,
+    #[msg("Lobby has not expired yet")]
+    LobbyNotExpired,
+    #[msg("Missing or invalid refund account")]
+    InvalidRefundAccount,
+    #[msg("Game already started")]
+    GameAlreadyStarted,
+    #[msg("Pot has not been paid out yet")]
+    PayoutsPending,
+    #[msg("Only the creator can close the game before the grace period ends")]
+    CloseGracePeriod,
+    #[msg("Player is out of the game")]
+    PlayerOut,
+    #[msg("Already joined this game")]
+    AlreadyJoined,
+    #[msg("Profile is too new to join this game")]
+    ProfileTooNew,
+    #[msg("Not enough games played to join this game")]
+    NotEnoughGamesPlayed,
+    #[msg("Out of time, the move can only be penalized")]
+    OutOfTime,
+    #[msg("Player on turn is not overdue yet")]
+    TurnNotOverdue,
+    #[msg("Paused by the admin")]
+    Paused,
+    #[msg("No keeper reward is owed to this signer")]
+    NoKeeperReward,
+    #[msg("Round in progress, it is already dealt")]
+    RoundInProgress,
+    #[msg("The next round is already being dealt")]
+    DealInProgress,
+    #[msg("Dealer is not overdue yet")]
+    DealerNotOverdue,
+    #[msg("Profile accounts must follow the seat order")]
+    InvalidProfileAccount
+}
+
+impl From<EngineError> for GameErrors {
+    fn from(error: EngineError) -> Self {
+        match error {
+            EngineError::InvalidNoPlayers => GameErrors::InvalidNoPlayers,
+            EngineError::InvalidRuleSet => GameErrors::InvalidRuleSet,
+            EngineError::NotYourTurn => GameErrors::NotYourTurn,
+            EngineError::CannotPlayCard => GameErrors::CannotPlayCard,
+            EngineError::NoCallCard => GameErrors::NoCallCard,
+            EngineError::NoDrawPile => GameErrors::NoDrawPile,
+            EngineError::PlayerNotFound => GameErrors::PlayerNotFound,
+            EngineError::GameNotStarted => GameErrors::GameNotStarted,
+            EngineError::GameEnded => GameErrors::GameEnded,
+            EngineError::RoundEnded => GameErrors::RoundEnded,
+            EngineError::CannotPenalizeYourself => GameErrors::CannotPenalizeYourself,
+            EngineError::InvalidNeed => GameErrors::InvalidNeed,
+            EngineError::NoPendingPenalty => GameErrors::NoPendingPenalty,
+            EngineError::PenaltyPending => GameErrors::PenaltyPending,
+            EngineError::InvalidCard => GameErrors::InvalidCard,
+            EngineError::CardNotInHand => GameErrors::CardNotInHand,
+            EngineError::AwaitingReveal => GameErrors::AwaitingReveal,
+            EngineError::CallCardHidden => GameErrors::CallCardHidden,
+            EngineError::CardNotHidden => GameErrors::CardNotHidden,
+            EngineError::PlayerOut => GameErrors::PlayerOut,
+            EngineError::OutOfTime => GameErrors::OutOfTime,
+            EngineError::TurnNotOverdue => GameErrors::TurnNotOverdue,
+            EngineError::RoundInProgress => GameErrors::RoundInProgress
+        }
+    }
+}
@@ -3,5 +3,7 @@ use anchor_lang::prelude::*;
 #[error_code]
 pub enum ProfileErrors{
     #[msg("Username should be between 3 and 32 characters")]
-    InvalidUsername
+    InvalidUsername,
+    #[msg("Account is not a profile")]
+    InvalidProfile
 }
\ No newline at end of file
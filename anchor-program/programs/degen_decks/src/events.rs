@@ -0,0 +1,237 @@
+use anchor_lang::prelude::*;
+use crate::state::{Card, Game, PauseFlags, RuleSet};
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, PartialEq)]
+pub enum SpecialEffect {
+    HoldOn,
+    PickTwo,
+    PickThree,
+    Suspension,
+    GeneralMarket,
+    Need
+}
+
+impl From<whot_engine::Effect> for SpecialEffect {
+    fn from(effect: whot_engine::Effect) -> Self {
+        match effect {
+            whot_engine::Effect::HoldOn => SpecialEffect::HoldOn,
+            whot_engine::Effect::PickTwo => SpecialEffect::PickTwo,
+            whot_engine::Effect::PickThree => SpecialEffect::PickThree,
+            whot_engine::Effect::Suspension => SpecialEffect::Suspension,
+            whot_engine::Effect::GeneralMarket => SpecialEffect::GeneralMarket,
+            whot_engine::Effect::Need => SpecialEffect::Need
+        }
+    }
+}
+
+#[event]
+pub struct GameCreated {
+    pub game: Pubkey,
+    pub owner: Pubkey,
+    pub seed: u64,
+    pub stake_mint: Pubkey,
+    pub entry_stake: u64,
+    pub no_players: u8,
+    pub wait_time: i64,
+    pub rules: RuleSet,
+    pub created_at: i64
+}
+
+#[event]
+pub struct PlayerJoined {
+    pub game: Pubkey,
+    pub player: Pubkey,
+    pub username: String,
+    pub players: u8
+}
+
+#[event]
+pub struct PlayerExited {
+    pub game: Pubkey,
+    pub player: Pubkey,
+    pub players: u8,
+    pub cancelled: bool // the last player left, so the game was called off
+}
+
+#[event]
+pub struct OwnerChanged {
+    pub game: Pubkey,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey
+}
+
+#[event]
+pub struct DeckDealt {
+    pub game: Pubkey,
+    pub round: u8,
+    pub random_seed: u64,
+    pub deck_commitment: [u8; 32], // hash of the shuffled deck, or the dealer's root for hidden hands
+    pub call_card: Option<Card>,
+    pub player_turn: u8,
+    pub dealt_at: i64
+}
+
+#[event]
+pub struct CallCardRevealed {
+    pub game: Pubkey,
+    pub round: u8,
+    pub position: u8,
+    pub card: Card
+}
+
+#[event]
+pub struct DeckRevealed {
+    pub game: Pubkey,
+    pub round: u8,
+    pub secret: [u8; 32], // with the move log deck, lets anyone check the dealer's root
+    pub card_counts: Vec<u8>,
+    pub revealed_at: i64
+}
+
+#[event]
+pub struct PlayerResigned {
+    pub game: Pubkey,
+    pub player: Pubkey,
+    pub seat: u8,
+    pub players_left: u8
+}
+
+#[event]
+pub struct CardPlayed {
+    pub game: Pubkey,
+    pub player: Pubkey,
+    pub seat: u8,
+    pub card: Card,
+    pub need: Option<u8>,
+    pub cards_left: u8
+}
+
+#[event]
+pub struct CardDrawn {
+    pub game: Pubkey,
+    pub player: Pubkey,
+    pub seat: u8,
+    pub cards_drawn: u8,
+    pub market_left: u8
+}
+
+#[event]
+pub struct TurnTimedOut {
+    pub game: Pubkey,
+    pub keeper: Pubkey,
+    pub reward: u64 // owed out of the platform fee, see `claim_keeper_reward`
+}
+
+#[event]
+pub struct KeeperRewardClaimed {
+    pub game: Pubkey,
+    pub keeper: Pubkey,
+    pub amount: u64
+}
+
+#[event]
+pub struct PenaltyApplied {
+    pub game: Pubkey,
+    pub player: Pubkey,
+    pub seat: u8,
+    pub cards_drawn: u8,
+    pub penalized_by: Option<Pubkey> // None when the player accepted a pick penalty
+}
+
+#[event]
+pub struct SpecialEffectTriggered {
+    pub game: Pubkey,
+    pub seat: u8,
+    pub effect: SpecialEffect,
+    pub requested_shape: Option<u8>,
+    pub pending_pick: u8
+}
+
+#[event]
+pub struct GameEnded {
+    pub game: Pubkey,
+    pub winner: Option<Pubkey>,
+    pub winning_team: Option<u8>,
+    pub card_counts: Vec<u8>,
+    pub scores: Vec<u16>,
+    pub rounds_played: u8,
+    pub ended_at: i64
+}
+
+impl GameEnded {
+    pub fn new(key: Pubkey, game: &Game) -> Self {
+        GameEnded {
+            game: key,
+            winner: game.winner,
+            winning_team: game.winning_team,
+            card_counts: game.players.iter().map(|p| p.card_count.unwrap_or(0)).collect(),
+            scores: game.players.iter().map(|p| p.score).collect(),
+            rounds_played: game.round,
+            ended_at: game.ended_at.unwrap_or_default()
+        }
+    }
+}
+
+#[event]
+pub struct PrizeClaimed {
+    pub game: Pubkey,
+    pub player: Pubkey,
+    pub amount: u64,
+    pub refund: bool
+}
+
+#[event]
+pub struct GameClosed {
+    pub game: Pubkey,
+    pub closed_by: Pubkey,
+    pub dust: u64 // left in the vault after payouts, sent to the fee wallet
+}
+
+#[event]
+pub struct FeePaid {
+    pub game: Pubkey,
+    pub fee_ata: Pubkey,
+    pub amount: u64
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub admin: Pubkey,
+    pub platform_fee: u16,
+    pub keeper_reward: u16,
+    pub fee_wallet: Pubkey,
+    pub allowed_mints: Vec<Pubkey>
+}
+
+#[event]
+pub struct AdminProposed {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey
+}
+
+#[event]
+pub struct DealerChanged {
+    pub previous_dealer: Pubkey,
+    pub dealer: Pubkey
+}
+
+#[event]
+pub struct PauseChanged {
+    pub admin: Pubkey,
+    pub paused: PauseFlags
+}
+
+#[event]
+pub struct MintConfigUpdated {
+    pub mint: Pubkey,
+    pub min_stake: u64,
+    pub max_stake: u64,
+    pub stake_tiers: Vec<u64>,
+    pub platform_fee: Option<u16>
+}
@@ -0,0 +1,32 @@
+use crate::constants::CONFIG_SEED;
+use crate::errors::ConfigErrors;
+use crate::events::AdminTransferred;
+use crate::state::Config;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub new_admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+        constraint = config.pending_admin == Some(new_admin.key()) @ ConfigErrors::NoPendingAdmin
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> AcceptAdmin<'info> {
+    /// Second step of the admin handover proposed with `transfer_admin`
+    pub fn accept_admin(&mut self) -> Result<()> {
+        let previous_admin = self.config.admin;
+        self.config.admin = self.new_admin.key();
+        self.config.pending_admin = None;
+
+        emit!(AdminTransferred {
+            previous_admin,
+            new_admin: self.config.admin
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use whot_engine::{Action, Move};
+use crate::{
+    constants::{
+        CONFIG_SEED, 
+        GAME_SEED, 
+        MOVE_LOG_SEED, 
+        PROFILE_SEED
+    }, 
+    errors::GameErrors, 
+    events::{
+        GameEnded, 
+        PenaltyApplied
+    }, 
+    state::{
+        Config, 
+        Game, 
+        MoveLog, 
+        Profile
+    }
+};
+
+#[derive(Accounts)]
+pub struct AcceptPenalty<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        seeds = [
+            &PROFILE_SEED.as_bytes(), 
+            signer.key().as_ref()
+            ],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, Profile>,
+    #[account(
+        mut,
+        seeds = [
+            &GAME_SEED.as_bytes(), 
+            game.seed.to_le_bytes().as_ref(), 
+            game.creator.as_ref()
+            ],
+        bump = game.bump
+    )]
+    game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [
+            &MOVE_LOG_SEED.as_bytes(), 
+            game.key().as_ref()
+            ],
+        bump = move_log.bump
+    )]
+    pub move_log: Account<'info, MoveLog>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>
+}
+
+impl<'info> AcceptPenalty<'info> {
+    pub fn accept_penalty(&mut self) -> Result<()> {
+        require!(!self.config.paused.moves, GameErrors::Paused);
+        self.game.catch_up_pause(&self.config, &mut self.move_log)?;
+        let seat = self.game.seat_of(&self.signer.key())?;
+        let hand_len = self.game.hand_len(seat);
+        let mv = Move::new(seat, Action::AcceptPenalty);
+        let now = Clock::get()?.unix_timestamp;
+        self.game.apply_move(mv, now)?;
+        self.move_log.record_move(mv, now)?;
+
+        let game = self.game.key();
+        emit!(PenaltyApplied {
+            game,
+            player: self.signer.key(),
+            seat,
+            cards_drawn: self.game.hand_len(seat).saturating_sub(hand_len),
+            penalized_by: None
+        });
+        if self.game.ended {
+            emit!(GameEnded::new(game, &self.game));
+        }
+
+        Ok(())
+    }
+}
\ No newline at end of file
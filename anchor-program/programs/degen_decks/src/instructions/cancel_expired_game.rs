@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ Mint, TokenInterface, TokenAccount };
+use crate::constants::GAME_SEED;
+use crate::errors::GameErrors;
+use crate::events::{GameEnded, PrizeClaimed};
+use crate::state::Game;
+use crate::utils::spl_transfer;
+
+
+/// Anyone can call off a lobby that was not dealt before it expired.
+/// The stake token accounts of the seated players are passed as remaining
+/// accounts, in seat order.
+#[derive(Accounts)]
+pub struct CancelExpiredGame<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            &GAME_SEED.as_bytes(), 
+            game.seed.to_le_bytes().as_ref(), 
+            game.creator.as_ref()
+            ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        associated_token::mint = stake_mint,
+        associated_token::authority = game,
+        associated_token::token_program = token_program
+    )]
+    pub game_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mint::token_program = token_program
+    )]
+    pub stake_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> CancelExpiredGame<'info> {
+    pub fn cancel_expired_game(&mut self, user_atas: &'info [AccountInfo<'info>]) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(!self.game.started, GameErrors::GameAlreadyStarted);
+        require!(!self.game.ended, GameErrors::GameEnded);
+        require!(now >= self.game.lobby_expires_at, GameErrors::LobbyNotExpired);
+        require!(
+            user_atas.len() == self.game.players.len(),
+            GameErrors::InvalidRefundAccount
+        );
+
+        let game_seed =  self.game.seed.to_le_bytes();
+        let game_creator = self.game.creator;
+        let signer_seeds: &[&[&[u8]]]  = &[&[   
+            GAME_SEED.as_bytes(), 
+            game_seed.as_ref(), 
+            game_creator.as_ref(),
+            &[self.game.bump]
+        ]];
+
+        let game = self.game.key();
+        let entry_stake = self.game.entry_stake;
+        for (index, user_ata) in user_atas.iter().enumerate() {
+            // refunds only go to the player's own stake account
+            let owner = self.game.players[index].owner;
+            let token_account = InterfaceAccount::<TokenAccount>::try_from(user_ata)?;
+            require!(
+                token_account.owner == owner && token_account.mint == self.stake_mint.key(),
+                GameErrors::InvalidRefundAccount
+            );
+            if self.game.players[index].claimed {
+                continue;
+            }
+
+            spl_transfer(
+                self.game_vault.to_account_info(), 
+                user_ata.clone(), 
+                self.game.to_account_info(), 
+                self.token_program.to_account_info(), 
+                entry_stake, 
+                Some(signer_seeds)
+            )?;
+            self.game.players[index].claimed = true;
+
+            emit!(PrizeClaimed {
+                game,
+                player: owner,
+                amount: entry_stake,
+                refund: true
+            });
+        }
+
+        self.game.ended = true;
+        self.game.winner = None;
+        self.game.ended_at = Some(now);
+        emit!(GameEnded::new(game, &self.game));
+        Ok(())
+    }
+}
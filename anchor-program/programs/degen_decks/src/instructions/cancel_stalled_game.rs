@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::GAME_SEED,
+    errors::GameErrors,
+    events::GameEnded,
+    state::Game
+};
+
+
+/// Anyone can call off a hidden hands game once the dealer kept the table
+/// waiting for `DEALER_TIMEOUT`. The game ends as a draw, the players still
+/// in share the pot through `claim_prize`.
+#[derive(Accounts)]
+pub struct CancelStalledGame<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            &GAME_SEED.as_bytes(),
+            game.seed.to_le_bytes().as_ref(),
+            game.creator.as_ref()
+            ],
+        bump = game.bump
+    )]
+    game: Account<'info, Game>
+}
+
+impl<'info> CancelStalledGame<'info> {
+    pub fn cancel_stalled_game(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(self.game.dealer_overdue(now), GameErrors::DealerNotOverdue);
+        self.game.call_off(now);
+
+        emit!(GameEnded::new(self.game.key(), &self.game));
+        Ok(())
+    }
+}
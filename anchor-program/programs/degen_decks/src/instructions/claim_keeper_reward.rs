@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{ Mint, TokenInterface, TokenAccount }
+};
+use crate::{
+    constants::{
+        CONFIG_SEED, GAME_SEED
+    },
+    errors::GameErrors,
+    events::KeeperRewardClaimed,
+    state::{
+        Config, Game
+    }, utils::spl_transfer
+};
+
+/// Pays a keeper what it was owed by `crank_timeout`, once the game is over
+/// and back on the base layer
+#[derive(Accounts)]
+pub struct ClaimKeeperReward<'info> {
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            &GAME_SEED.as_bytes(),
+            game.seed.to_le_bytes().as_ref(),
+            game.creator.as_ref()
+            ],
+        bump = game.bump
+    )]
+    game: Account<'info, Game>,
+    #[account(
+        mut,
+        associated_token::mint = stake_mint,
+        associated_token::authority = game,
+        associated_token::token_program = token_program
+    )]
+    pub game_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = stake_mint,
+        associated_token::authority = keeper,
+        associated_token::token_program = token_program
+    )]
+    pub keeper_ata: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        seeds = [
+            &CONFIG_SEED.as_bytes()
+        ],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        address = game.stake_mint,
+        mint::token_program = token_program
+    )]
+    pub stake_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimKeeperReward<'info> {
+    pub fn claim_keeper_reward(&mut self) -> Result<()> {
+        require!(!self.config.paused.claims, GameErrors::Paused);
+        require!(self.game.ended, GameErrors::GameNotEnded);
+        let index = self.game.keepers
+            .iter()
+            .position(|owed| owed.keeper == self.keeper.key())
+            .ok_or(GameErrors::NoKeeperReward)?;
+        let amount = self.game.keepers[index].amount;
+
+        let game_seed =  self.game.seed.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]]  = &[&[
+            GAME_SEED.as_bytes(),
+            game_seed.as_ref(),
+            self.game.creator.as_ref(),
+            &[self.game.bump]
+        ]];
+        spl_transfer(
+            self.game_vault.to_account_info(),
+            self.keeper_ata.to_account_info(),
+            self.game.to_account_info(),
+            self.token_program.to_account_info(),
+            amount,
+            Some(signer_seeds)
+        )?;
+        self.game.keepers.remove(index);
+
+        emit!(KeeperRewardClaimed {
+            game: self.game.key(),
+            keeper: self.keeper.key(),
+            amount
+        });
+        Ok(())
+    }
+}
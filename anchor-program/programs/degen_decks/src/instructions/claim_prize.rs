@@ -8,6 +8,9 @@ use crate::{
         CONFIG_SEED, GAME_SEED, PROFILE_SEED
     }, 
     errors::GameErrors, 
+    events::{
+        FeePaid, PrizeClaimed
+    }, 
     state::{
         Config, Game, Profile
     }, utils::spl_transfer
@@ -30,7 +33,7 @@ pub struct ClaimPrize<'info> {
         seeds = [
             &GAME_SEED.as_bytes(), 
             game.seed.to_le_bytes().as_ref(), 
-            game.owner.as_ref()
+            game.creator.as_ref()
             ],
         bump = game.bump
     )]
@@ -71,27 +74,76 @@ pub struct ClaimPrize<'info> {
 
 impl<'info> ClaimPrize<'info> {
     pub fn claim_prize(&mut self) -> Result<()> {
-        require!(self.game.ended == true, GameErrors::GameNotEnded);
+        require!(!self.config.paused.claims, GameErrors::Paused);
+        require!(self.game.ended, GameErrors::GameNotEnded);
         // check if player has claimed
         let player_index = self.game.players.iter().position(|p| p.owner == self.signer.key()).ok_or(GameErrors::PlayerNotFound)?;
-        require!(self.game.players[player_index].claimed == false, GameErrors::AlreadyClaimed);
+        require!(!self.game.players[player_index].claimed, GameErrors::AlreadyClaimed);
+        require!(!self.game.players[player_index].out, GameErrors::PlayerOut);
         require!(self.fee_ata.owner == self.config.fee_wallet, GameErrors::InvalidAuthority);
 
 
         let game_seed =  self.game.seed.to_le_bytes();
         let signer_seeds: &[&[&[u8]]]  = &[&[   
-            &GAME_SEED.as_bytes(), 
+            GAME_SEED.as_bytes(), 
             game_seed.as_ref(), 
-            self.game.owner.as_ref(),
+            self.game.creator.as_ref(),
             &[self.game.bump]
         ]];
         // send out prize to winner(s)
         let winner = self.game.winner;
-        if winner.is_some(){
+        if let Some(winning_team) = self.game.winning_team {
+            let player = &self.game.players[player_index];
+            require!(self.game.team_of(player) == Some(winning_team), GameErrors::YouAreNotWinner);
+            // each partner still in gets an equal share of the pot and pays
+            // the fee on it
+            let share = self.game.entry_stake * self.game.no_players as u64 / self.game.payees().count() as u64;
+            let fee_amount = share * self.game.platform_fee as u64 / 10_000;
+            let amount = share - fee_amount;
+            // keepers are owed part of the fee
+            let keeper_rewards = self.game.keeper_rewards.min(fee_amount);
+            let fee_amount = fee_amount - keeper_rewards;
+            // transfer prize - fee
+            spl_transfer(
+                self.game_vault.to_account_info(), 
+                self.user_ata.to_account_info(), 
+                self.game.to_account_info(), 
+                self.token_program.to_account_info(), 
+                amount, 
+                Some(signer_seeds)
+            )?;
+            // transfer fee
+            spl_transfer(
+                self.game_vault.to_account_info(), 
+                self.fee_ata.to_account_info(), 
+                self.game.to_account_info(), 
+                self.token_program.to_account_info(), 
+                fee_amount, 
+                Some(signer_seeds)
+            )?;
+            // mark player as claimed
+            self.game.players[player_index].claimed = true;
+            self.game.keeper_rewards -= keeper_rewards;
+
+            emit!(PrizeClaimed {
+                game: self.game.key(),
+                player: self.signer.key(),
+                amount,
+                refund: false
+            });
+            emit!(FeePaid {
+                game: self.game.key(),
+                fee_ata: self.fee_ata.key(),
+                amount: fee_amount
+            });
+        }
+        else if winner.is_some(){
             require!(self.signer.key() == winner.unwrap(), GameErrors::YouAreNotWinner);
-            // calculate fee
-            let fee_amount = self.game_vault.amount * self.config.platform_fee as u64 / 10_000;
-            let amount = self.game_vault.amount - fee_amount;
+            // calculate fee on the whole pot, keepers are owed part of it
+            let pot = self.game.entry_stake * self.game.players.len() as u64;
+            let fee_amount = pot * self.game.platform_fee as u64 / 10_000;
+            let amount = pot - fee_amount;
+            let fee_amount = fee_amount - self.game.keeper_rewards.min(fee_amount);
             // transfer prize - fee
             spl_transfer(
                 self.game_vault.to_account_info(), 
@@ -112,9 +164,29 @@ impl<'info> ClaimPrize<'info> {
             )?;
             // mark player as claimed
             self.game.players[player_index].claimed = true;
+            self.game.keeper_rewards = 0;
+
+            emit!(PrizeClaimed {
+                game: self.game.key(),
+                player: self.signer.key(),
+                amount,
+                refund: false
+            });
+            emit!(FeePaid {
+                game: self.game.key(),
+                fee_ata: self.fee_ata.key(),
+                amount: fee_amount
+            });
         }
         else if winner.is_none() {
-            let amount = self.game.entry_stake;
+            // stakes forfeited by resigned players are shared on a draw, each
+            // share pays the fee as on a win so keepers are paid out of the
+            // fee and never out of the stakes
+            let share = self.game.entry_stake * self.game.players.len() as u64 / self.game.payees().count() as u64;
+            let fee_amount = share * self.game.platform_fee as u64 / 10_000;
+            let amount = share - fee_amount;
+            let keeper_rewards = self.game.keeper_rewards.min(fee_amount);
+            let fee_amount = fee_amount - keeper_rewards;
             spl_transfer(
                 self.game_vault.to_account_info(), 
                 self.user_ata.to_account_info(), 
@@ -123,9 +195,30 @@ impl<'info> ClaimPrize<'info> {
                 amount, 
                 Some(signer_seeds)
             )?;
+            spl_transfer(
+                self.game_vault.to_account_info(), 
+                self.fee_ata.to_account_info(), 
+                self.game.to_account_info(), 
+                self.token_program.to_account_info(), 
+                fee_amount, 
+                Some(signer_seeds)
+            )?;
 
             // mark player as claimed
             self.game.players[player_index].claimed = true;
+            self.game.keeper_rewards -= keeper_rewards;
+
+            emit!(PrizeClaimed {
+                game: self.game.key(),
+                player: self.signer.key(),
+                amount,
+                refund: true
+            });
+            emit!(FeePaid {
+                game: self.game.key(),
+                fee_ata: self.fee_ata.key(),
+                amount: fee_amount
+            });
         }
         Ok(())
     }
@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ close_account, CloseAccount, Mint, TokenInterface, TokenAccount };
+use crate::{
+    constants::{
+        CONFIG_SEED, GAME_SEED, MOVE_LOG_SEED
+    }, 
+    errors::GameErrors, 
+    events::GameClosed, 
+    state::{
+        Config, Game, MoveLog
+    }, utils::spl_transfer
+};
+
+/// Closes a finished game, its move log and its vault once the pot is paid
+/// out, returning the rent to the creator who paid it. The creator can close
+/// right away, anyone else can crank it after `CLOSE_GRACE_PERIOD`. Keeper
+/// rewards still unclaimed by then go to the fee wallet.
+#[derive(Accounts)]
+pub struct CloseGame<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    /// CHECK: receives the rent, checked against the game creator
+    #[account(
+        mut,
+        address = game.creator @ GameErrors::InvalidAuthority
+    )]
+    pub creator: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = creator,
+        seeds = [
+            &GAME_SEED.as_bytes(), 
+            game.seed.to_le_bytes().as_ref(), 
+            game.creator.as_ref()
+            ],
+        bump = game.bump
+    )]
+    game: Account<'info, Game>,
+    #[account(
+        mut,
+        close = creator,
+        seeds = [
+            &MOVE_LOG_SEED.as_bytes(), 
+            game.key().as_ref()
+            ],
+        bump = move_log.bump
+    )]
+    pub move_log: Account<'info, MoveLog>,
+    #[account(
+        mut,
+        associated_token::mint = stake_mint,
+        associated_token::authority = game,
+        associated_token::token_program = token_program
+    )]
+    pub game_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = stake_mint,
+        token::authority = config.fee_wallet,
+        token::token_program = token_program
+    )]
+    pub fee_ata: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        seeds = [
+            &CONFIG_SEED.as_bytes()
+        ],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        address = game.stake_mint,
+        mint::token_program = token_program
+    )]
+    pub stake_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> CloseGame<'info> {
+    pub fn sweep_dust(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(self.game.ended, GameErrors::GameNotEnded);
+        require!(
+            self.game.payouts_done(now) || self.game_vault.amount == 0,
+            GameErrors::PayoutsPending
+        );
+        if self.signer.key() != self.game.creator {
+            require!(self.game.close_grace_over(now), GameErrors::CloseGracePeriod);
+        }
+
+        // whatever is left once everyone was paid, e.g. tokens sent to
+        // the vault directly or keeper rewards never claimed, goes to the
+        // fee wallet so the vault can close
+        let dust = self.game_vault.amount;
+        if dust > 0 {
+            let game_seed =  self.game.seed.to_le_bytes();
+            let signer_seeds: &[&[&[u8]]]  = &[&[   
+                GAME_SEED.as_bytes(), 
+                game_seed.as_ref(), 
+                self.game.creator.as_ref(),
+                &[self.game.bump]
+            ]];
+            spl_transfer(
+                self.game_vault.to_account_info(), 
+                self.fee_ata.to_account_info(), 
+                self.game.to_account_info(), 
+                self.token_program.to_account_info(), 
+                dust, 
+                Some(signer_seeds)
+            )?;
+        }
+
+        emit!(GameClosed {
+            game: self.game.key(),
+            closed_by: self.signer.key(),
+            dust
+        });
+        Ok(())
+    }
+
+    pub fn close_vault(&mut self) -> Result<()> {
+        let game_seed =  self.game.seed.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]]  = &[&[   
+            GAME_SEED.as_bytes(), 
+            game_seed.as_ref(), 
+            self.game.creator.as_ref(),
+            &[self.game.bump]
+        ]];
+
+        close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.game_vault.to_account_info(),
+                destination: self.creator.to_account_info(),
+                authority: self.game.to_account_info()
+            },
+            signer_seeds
+        ))
+    }
+}
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use crate::{constants::{
+    CONFIG_SEED, GAME_SEED, MOVE_LOG_SEED
+}, instruction::ConsumeRandomness};
+use crate::errors::GameErrors;
+use crate::state::{Config, Game, MoveLog};
+use ephemeral_vrf_sdk::anchor::vrf;
+use ephemeral_vrf_sdk::instructions::{create_request_randomness_ix, RequestRandomnessParams};
+use ephemeral_vrf_sdk::types::SerializableAccountMeta;
+
+
+#[vrf]
+#[derive(Accounts)]
+pub struct CommitDeck<'info> {
+    #[account(
+        mut,
+        address = config.dealer @ GameErrors::InvalidAuthority
+    )]
+    pub dealer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            &GAME_SEED.as_bytes(), 
+            game.seed.to_le_bytes().as_ref(), 
+            game.creator.as_ref()
+            ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        seeds = [
+            &MOVE_LOG_SEED.as_bytes(), 
+            game.key().as_ref()
+            ],
+        bump = move_log.bump
+    )]
+    pub move_log: Account<'info, MoveLog>,
+    #[account(
+        seeds = [
+            &CONFIG_SEED.as_bytes()
+        ],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    /// CHECK: The oracle queue
+    #[account(mut, address = ephemeral_vrf_sdk::consts::DEFAULT_QUEUE)]
+    pub oracle_queue: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CommitDeck<'info> {
+    pub fn commit_deck(&mut self, deck_root: [u8; 32]) -> Result<()> {
+        require!(self.game.rules.hidden_hands, GameErrors::InvalidRuleSet);
+        require!(
+            self.game.players.len() == self.game.no_players as usize,
+            GameErrors::WaitingForPlayers
+        );
+        require!(!self.game.ended, GameErrors::GameEnded);
+        require!(
+            !self.game.started || self.game.round_ended,
+            GameErrors::RoundNotEnded
+        );
+        require!(self.game.deck_root.is_none(), GameErrors::DeckAlreadyCommitted);
+
+        // the root is fixed before the VRF decides who gets which position
+        self.game.deck_root = Some(deck_root);
+        self.game.track_dealer(Clock::get()?.unix_timestamp);
+        Ok(())
+    }
+
+    pub fn request_randomness(&mut self) -> Result<()> {
+        let mut caller_seed = [5; 32];
+        caller_seed[0] = self.game.round;
+        let first_deal = !self.game.started;
+        let ix = create_request_randomness_ix(
+            RequestRandomnessParams {
+                payer: self.dealer.key(),
+                oracle_queue: self.oracle_queue.key(),
+                callback_program_id: crate::ID,
+                callback_discriminator: ConsumeRandomness::DISCRIMINATOR.to_vec(),
+                caller_seed,
+                accounts_metas: Some(
+                    vec![
+                        SerializableAccountMeta {
+                            pubkey: self.game.key(),
+                            is_signer: false,
+                            is_writable: true
+                        },
+                        SerializableAccountMeta {
+                            pubkey: self.move_log.key(),
+                            is_signer: false,
+                            is_writable: true
+                        }
+                    ]
+                    .into_iter()
+                    // the first deal counts the game for every seat
+                    .chain(self.game.profiles().filter(|_| first_deal).map(|pubkey| {
+                        SerializableAccountMeta {
+                            pubkey,
+                            is_signer: false,
+                            is_writable: true
+                        }
+                    }))
+                    .collect()
+                ),
+                ..Default::default()
+            }
+        );
+        self.invoke_signed_vrf(&self.dealer.to_account_info(), &ix)?;
+        Ok(())
+    }
+}
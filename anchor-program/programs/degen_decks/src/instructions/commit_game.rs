@@ -5,10 +5,12 @@ use ephemeral_rollups_sdk::{
 };
 use crate::{
     constants::{
-        GAME_SEED
+        GAME_SEED, 
+        MOVE_LOG_SEED
     }, 
     state::{
-        Game
+        Game, 
+        MoveLog
     },
 };
 
@@ -22,24 +24,33 @@ pub struct CommitGame<'info> {
         seeds = [
             &GAME_SEED.as_bytes(), 
             game.seed.to_le_bytes().as_ref(), 
-            game.owner.as_ref()
+            game.creator.as_ref()
             ],
         bump = game.bump
     )]
     game: Account<'info, Game>,
-
+    #[account(
+        mut,
+        seeds = [
+            &MOVE_LOG_SEED.as_bytes(), 
+            game.key().as_ref()
+            ],
+        bump = move_log.bump
+    )]
+    move_log: Account<'info, MoveLog>,
 }
 
 impl<'info> CommitGame<'info> {
     pub fn commit_game(&mut self) -> Result<()> {
 
-        if self.game.ended && self.game.delegated {
+        if (self.game.ended || self.game.round_ended) && self.game.delegated {
             self.game.delegated = false;
             // commit and undelegate
             self.game.exit(&crate::ID)?;
+            self.move_log.exit(&crate::ID)?;
             commit_and_undelegate_accounts(
                 &self.signer,
-                vec![&self.game.to_account_info()],
+                vec![&self.game.to_account_info(), &self.move_log.to_account_info()],
                 &self.magic_context,
                 &self.magic_program,
             )?;
@@ -1,9 +1,10 @@
 use anchor_lang::prelude::*;
 use ephemeral_vrf_sdk;
 
-use crate::constants::{GAME_SEED, NO_SHARED_CARDS};
-use crate::state::{Game};
-use crate::utils::shuffle_cards;
+use crate::constants::{GAME_SEED, MOVE_LOG_SEED};
+use crate::errors::GameErrors;
+use crate::events::DeckDealt;
+use crate::state::{Game, MoveLog, Profile};
 
 #[derive(Accounts)]
 pub struct ConsumeRandomness<'info> {
@@ -16,53 +17,72 @@ pub struct ConsumeRandomness<'info> {
         seeds = [
             &GAME_SEED.as_bytes(), 
                 game.seed.to_le_bytes().as_ref(), 
-                game.owner.as_ref()
+                game.creator.as_ref()
             ],
         bump = game.bump
     )]
-    pub game: Account<'info, Game>
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [
+            &MOVE_LOG_SEED.as_bytes(), 
+            game.key().as_ref()
+            ],
+        bump = move_log.bump
+    )]
+    pub move_log: Account<'info, MoveLog>
 }
 
 impl<'info> ConsumeRandomness<'info> {
-    pub fn consume_randomness(&mut self, randomness: [u8; 32]) -> Result<()> {
+    /// The first deal also counts the game for every seat, their profiles
+    /// are passed as remaining accounts in seat order
+    pub fn consume_randomness(&mut self, randomness: [u8; 32], profiles: &'info [AccountInfo<'info>]) -> Result<()> {
+        // hidden hands are dealt as positions of the dealer's deck
+        require!(
+            !self.game.rules.hidden_hands || self.game.deck_root.is_some(),
+            GameErrors::DeckNotCommitted
+        );
+
         //derive a u64 seed from the randomness
         let rnd_u64 = ephemeral_vrf_sdk::rnd::random_u64(&randomness);
 
-        //get a shuffled full deck
-        let shuffled_cards = shuffle_cards(rnd_u64);
-
-        let num_players = self.game.no_players as usize;
-        let cards_per_player = NO_SHARED_CARDS as usize;
-        let total_to_share = num_players * cards_per_player;
+        //shuffle and deal the next round
+        let now = Clock::get()?.unix_timestamp;
+        let first_deal = !self.game.started;
+        self.game.deal(rnd_u64, now)?;
+        self.game.dealing = false;
+        self.move_log.record_deal(rnd_u64, now)?;
 
-        //slice out the part of the deck for sharing
-        let cards_to_share = &shuffled_cards[0..total_to_share];
-        let call_card = shuffled_cards[total_to_share].clone();
-        let draw_pile = shuffled_cards[total_to_share + 1..].to_vec();
+        emit!(DeckDealt {
+            game: self.game.key(),
+            round: self.game.round,
+            random_seed: rnd_u64,
+            deck_commitment: self.game.deck_commitment().unwrap_or_default(),
+            call_card: self.game.call_card,
+            player_turn: self.game.player_turn,
+            dealt_at: now
+        });
 
-        //distribute cards to each player
-        for (i, player) in self.game.players.iter_mut().enumerate() {
-            let start = i * cards_per_player;
-            let end = start + cards_per_player;
-            let hand_cards = cards_to_share[start..end].to_vec();
-
-            player.hand = Some(hand_cards);
-            player.player_index = Some((i + 1) as u8);
+        if first_deal {
+            self.count_game_played(profiles)?;
         }
-
-        //assign call card and draw pile
-        self.game.call_card = Some(call_card);
-        self.game.draw_pile = Some(draw_pile);
-        
-
-        //update game state
-        self.game.random_seed = Some(rnd_u64);
-        self.game.started = true;
-        self.game.started_at = Some(Clock::get()?.unix_timestamp);
-        self.game.player_turn = 1;
-        self.game.last_move_time = Some(Clock::get()?.unix_timestamp);
-
         Ok(())
     }
 
+    /// Profiles still in the layout before `games_played` cannot be grown
+    /// here, with no payer for the rent, so they are skipped until their
+    /// owner calls `migrate_profile`.
+    fn count_game_played(&self, profiles: &'info [AccountInfo<'info>]) -> Result<()> {
+        require!(profiles.len() == self.game.players.len(), GameErrors::InvalidProfileAccount);
+        for (account, expected) in profiles.iter().zip(self.game.profiles()) {
+            require!(account.key() == expected, GameErrors::InvalidProfileAccount);
+            if account.data_len() < Profile::DISCRIMINATOR.len() + Profile::INIT_SPACE {
+                continue;
+            }
+            let mut profile = Account::<Profile>::try_from(account)?;
+            profile.games_played = profile.games_played.saturating_add(1);
+            profile.exit(&crate::ID)?;
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use whot_engine::{Action, Move};
+use crate::{
+    constants::{
+        CONFIG_SEED,
+        GAME_SEED,
+        MOVE_LOG_SEED
+    },
+    errors::GameErrors,
+    events::{
+        GameEnded,
+        PenaltyApplied,
+        PlayerResigned,
+        TurnTimedOut
+    },
+    state::{
+        Config,
+        Game,
+        MoveLog
+    }
+};
+
+
+/// Permissionless `penalize_opponent` for stalled tables. Runs wherever the
+/// game is, on the ephemeral rollup once it is delegated, so the keeper is
+/// only owed the reward here and claims it with `claim_keeper_reward`.
+#[derive(Accounts)]
+pub struct CrankTimeout<'info> {
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            &GAME_SEED.as_bytes(),
+            game.seed.to_le_bytes().as_ref(),
+            game.creator.as_ref()
+            ],
+        bump = game.bump
+    )]
+    game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [
+            &MOVE_LOG_SEED.as_bytes(),
+            game.key().as_ref()
+            ],
+        bump = move_log.bump
+    )]
+    pub move_log: Account<'info, MoveLog>,
+    #[account(
+        seeds = [
+            &CONFIG_SEED.as_bytes()
+        ],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> CrankTimeout<'info> {
+    /// Penalize the overdue seat on turn, the same way its opponents would
+    pub fn crank_timeout(&mut self) -> Result<()> {
+        require!(!self.config.paused.moves, GameErrors::Paused);
+        self.game.catch_up_pause(&self.config, &mut self.move_log)?;
+        let seat = self.game.player_turn;
+        let hand_len = self.game.hand_len(seat);
+        let mv = Move::new(seat, Action::Timeout);
+        let now = Clock::get()?.unix_timestamp;
+        self.game.apply_move(mv, now)?;
+        self.move_log.record_move(mv, now)?;
+
+        let game = self.game.key();
+        let player = &self.game.players[seat as usize - 1];
+        emit!(PenaltyApplied {
+            game,
+            player: player.owner,
+            seat,
+            cards_drawn: self.game.hand_len(seat).saturating_sub(hand_len),
+            penalized_by: Some(self.keeper.key())
+        });
+        // a flagged seat, or one that keeps timing out, forfeits
+        if player.out {
+            emit!(PlayerResigned {
+                game,
+                player: player.owner,
+                seat,
+                players_left: self.game.players.iter().filter(|p| !p.out).count() as u8
+            });
+        }
+        if self.game.ended {
+            emit!(GameEnded::new(game, &self.game));
+        }
+        Ok(())
+    }
+
+    /// Owe the keeper its reward out of the platform fee the pot will
+    /// owe, what is owed here is taken off the fee at `claim_prize`
+    pub fn reward_keeper(&mut self) -> Result<()> {
+        let reward = self.game.keeper_reward(&self.config);
+        let reward = self.game.reward_keeper(self.keeper.key(), reward);
+
+        emit!(TurnTimedOut {
+            game: self.game.key(),
+            keeper: self.keeper.key(),
+            reward
+        });
+        Ok(())
+    }
+}
@@ -1,16 +1,25 @@
 use anchor_lang::prelude::*;
+use whot_engine::{Action, Move};
 use ephemeral_rollups_sdk::{
     anchor::{delegate}, 
     cpi::DelegateConfig
 };
 use crate::{
     constants::{
+        CONFIG_SEED, 
         GAME_SEED, 
+        MOVE_LOG_SEED, 
         PROFILE_SEED
     }, 
     errors::GameErrors, 
+    events::{
+        CardDrawn, 
+        GameEnded
+    }, 
     state::{
+        Config, 
         Game, 
+        MoveLog, 
         Profile
     }
 };
@@ -34,23 +43,50 @@ pub struct DrawFromPileDelegate<'info> {
         seeds = [
             &GAME_SEED.as_bytes(), 
             game.seed.to_le_bytes().as_ref(), 
-            game.owner.as_ref()
+            game.creator.as_ref()
             ],
         bump = game.bump
     )]
-    game: Account<'info, Game>
+    game: Account<'info, Game>,
+    #[account(
+        mut,
+        del,
+        seeds = [
+            &MOVE_LOG_SEED.as_bytes(), 
+            game.key().as_ref()
+            ],
+        bump = move_log.bump
+    )]
+    pub move_log: Account<'info, MoveLog>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>
 }
 
 impl<'info> DrawFromPileDelegate<'info> {
     pub fn draw_from_pile_delegate(&mut self) -> Result<()> {
-        let player = self.game.players.iter().find(|p| p.owner == self.signer.key()).ok_or(GameErrors::PlayerNotFound)?;
-        
-        require!(player.player_index == Some(self.game.player_turn), GameErrors::NotYourTurn);
-        require!(self.game.started == true, GameErrors::GameNotStarted);
-        require!(self.game.ended == false, GameErrors::GameEnded);
+        require!(!self.config.paused.moves, GameErrors::Paused);
+        self.game.catch_up_pause(&self.config, &mut self.move_log)?;
+        let seat = self.game.seat_of(&self.signer.key())?;
+        let hand_len = self.game.hand_len(seat);
+        let mv = Move::new(seat, Action::Draw);
+        let now = Clock::get()?.unix_timestamp;
+        self.game.apply_move(mv, now)?;
+        self.move_log.record_move(mv, now)?;
 
-        self.game.last_move_time = Some(Clock::get()?.unix_timestamp);
-        self.game.handle_draw_from_pile()?;
+        let game = self.game.key();
+        emit!(CardDrawn {
+            game,
+            player: self.signer.key(),
+            seat,
+            cards_drawn: self.game.hand_len(seat).saturating_sub(hand_len),
+            market_left: self.game.market_len()
+        });
+        if self.game.ended {
+            emit!(GameEnded::new(game, &self.game));
+        }
 
 
         if !self.game.delegated && !self.game.ended {
@@ -62,7 +98,18 @@ impl<'info> DrawFromPileDelegate<'info> {
                 &[
                     &GAME_SEED.as_bytes(), 
                     self.game.seed.to_le_bytes().as_ref(), 
-                    self.game.owner.as_ref()
+                    self.game.creator.as_ref()
+                ],
+                DelegateConfig {
+                    ..Default::default()
+                }
+            )?;
+            self.move_log.exit(&crate::ID)?;
+            self.delegate_move_log(
+                &self.signer,
+                &[
+                    MOVE_LOG_SEED.as_bytes(), 
+                    self.game.key().as_ref()
                 ],
                 DelegateConfig {
                     ..Default::default()
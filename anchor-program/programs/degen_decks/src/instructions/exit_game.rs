@@ -1,11 +1,12 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_interface::{ Mint, TokenInterface, TokenAccount }
+    token_interface::{ close_account, CloseAccount, Mint, TokenInterface, TokenAccount }
 };
-use crate::constants::{GAME_SEED, PROFILE_SEED};
+use crate::constants::{GAME_SEED, MOVE_LOG_SEED, PROFILE_SEED};
 use crate::errors::GameErrors;
-use crate::state::{Game, Profile};
+use crate::events::{GameEnded, OwnerChanged, PlayerExited};
+use crate::state::{Game, MoveLog, Profile};
 use crate::utils::spl_transfer;
 
 
@@ -26,11 +27,26 @@ pub struct ExitGame<'info> {
         seeds = [
             &GAME_SEED.as_bytes(), 
             game.seed.to_le_bytes().as_ref(), 
-            game.owner.as_ref()
+            game.creator.as_ref()
             ],
         bump = game.bump
     )]
     pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [
+            &MOVE_LOG_SEED.as_bytes(), 
+            game.key().as_ref()
+            ],
+        bump = move_log.bump
+    )]
+    pub move_log: Account<'info, MoveLog>,
+    /// CHECK: gets the rent back if the lobby empties, checked against the game creator
+    #[account(
+        mut,
+        address = game.creator @ GameErrors::InvalidAuthority
+    )]
+    pub creator: UncheckedAccount<'info>,
     #[account(
         mut,
         associated_token::mint = stake_mint,
@@ -56,23 +72,50 @@ pub struct ExitGame<'info> {
 
 impl<'info> ExitGame<'info> {
     pub fn exit_game(&mut self) -> Result<()> {
-        // check if player is in the list
-        require!(
-            self.game.players.iter().any(|player| player.owner == self.signer.key()),
-            GameErrors::PlayerNotFound
-        );
+        // check if player is in the list and was not refunded already
+        let player = self
+            .game
+            .players
+            .iter()
+            .find(|player| player.owner == self.signer.key())
+            .ok_or(GameErrors::PlayerNotFound)?;
+        require!(!player.claimed, GameErrors::AlreadyClaimed);
+        // seats of a dealt game are left with `resign`, which forfeits the stake
+        require!(!self.game.started, GameErrors::GameAlreadyStarted);
         require!(
             self.game_vault.amount >= self.game.entry_stake,
             GameErrors::InsufficientFunds
         );
 
-        if self.game.owner == self.signer.key() {
+        if let Some(index) = self.game.players.iter().position(|p| p.owner == self.signer.key()) {
+            self.game.players.remove(index);
+        }
+
+        // the lobby is only called off once everyone has left, an owner
+        // leaving hands the game to the next seated player
+        let game = self.game.key();
+        let cancelled = self.game.players.is_empty();
+        if cancelled {
             self.game.ended = true;
             self.game.winner = None;            
+            self.game.ended_at = Some(Clock::get()?.unix_timestamp);
+        } else if self.game.owner == self.signer.key() {
+            self.game.owner = self.game.players[0].owner;
+            emit!(OwnerChanged {
+                game,
+                previous_owner: self.signer.key(),
+                new_owner: self.game.owner
+            });
         }
 
-        if let Some(index) = self.game.players.iter().position(|p| p.owner == self.signer.key()) {
-            self.game.players.remove(index);
+        emit!(PlayerExited {
+            game,
+            player: self.signer.key(),
+            players: self.game.players.len() as u8,
+            cancelled
+        });
+        if cancelled {
+            emit!(GameEnded::new(game, &self.game));
         }
         Ok(())
     }
@@ -82,7 +125,7 @@ impl<'info> ExitGame<'info> {
         let signer_seeds: &[&[&[u8]]]  = &[&[   
             &GAME_SEED.as_bytes(), 
             game_seed.as_ref(), 
-            self.game.owner.as_ref(),
+            self.game.creator.as_ref(),
             &[self.game.bump]
         ]];
 
@@ -95,4 +138,33 @@ impl<'info> ExitGame<'info> {
             Some(signer_seeds)
         )
     }
+
+    /// Close an emptied lobby and return the rent to its creator. A vault
+    /// holding more than the refunded stakes is left for `close_game`.
+    pub fn close_lobby(&mut self) -> Result<()> {
+        self.game_vault.reload()?;
+        if self.game_vault.amount > 0 {
+            return Ok(());
+        }
+
+        let game_seed =  self.game.seed.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]]  = &[&[   
+            GAME_SEED.as_bytes(), 
+            game_seed.as_ref(), 
+            self.game.creator.as_ref(),
+            &[self.game.bump]
+        ]];
+        close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.game_vault.to_account_info(),
+                destination: self.creator.to_account_info(),
+                authority: self.game.to_account_info()
+            },
+            signer_seeds
+        ))?;
+
+        self.move_log.close(self.creator.to_account_info())?;
+        self.game.close(self.creator.to_account_info())
+    }
 }
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::GAME_SEED, 
+    state::{
+        Game, 
+        GameView
+    }
+};
+
+
+#[derive(Accounts)]
+pub struct GetGameView<'info> {
+    #[account(
+        seeds = [
+            &GAME_SEED.as_bytes(), 
+            game.seed.to_le_bytes().as_ref(), 
+            game.creator.as_ref()
+            ],
+        bump = game.bump
+    )]
+    game: Account<'info, Game>
+}
+
+impl<'info> GetGameView<'info> {
+    pub fn get_game_view(&self) -> Result<GameView> {
+        Ok(GameView::new(self.game.key(), &self.game))
+    }
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::GAME_SEED, 
+    state::{
+        Game, 
+        LegalMoves
+    }
+};
+
+
+#[derive(Accounts)]
+pub struct GetLegalMoves<'info> {
+    #[account(
+        seeds = [
+            &GAME_SEED.as_bytes(), 
+            game.seed.to_le_bytes().as_ref(), 
+            game.creator.as_ref()
+            ],
+        bump = game.bump
+    )]
+    game: Account<'info, Game>
+}
+
+impl<'info> GetLegalMoves<'info> {
+    pub fn get_legal_moves(&self, seat: u8) -> Result<LegalMoves> {
+        Ok(self.game.legal_moves(seat, Clock::get()?.unix_timestamp))
+    }
+}
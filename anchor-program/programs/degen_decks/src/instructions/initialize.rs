@@ -1,6 +1,6 @@
 use crate::constants::{CONFIG_SEED, MAX_MINTS_LENGTH};
 use crate::errors::ConfigErrors;
-use crate::state::Config;
+use crate::state::{Config, PauseFlags};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -8,7 +8,7 @@ pub struct Initialize<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
     #[account(
-        init_if_needed,
+        init,
         payer = admin,
         space = Config::DISCRIMINATOR.len() + Config::INIT_SPACE,
         seeds = [&CONFIG_SEED.as_bytes()],
@@ -17,6 +17,8 @@ pub struct Initialize<'info> {
     pub config: Account<'info, Config>,
     /// CHECK: fee wallet 
     pub fee_wallet: UncheckedAccount<'info>,
+    /// CHECK: dealer of hidden hand games
+    pub dealer: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
     // Making sure only the program update authority can add creators to the array
     #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ConfigErrors::NotAuthorized)]
@@ -24,11 +26,12 @@ pub struct Initialize<'info> {
 }
 
 impl<'info> Initialize<'info> {
-    pub fn initialize(&mut self, platform_fee: u16, allowed_mints: Vec<Pubkey>, bump: u8) -> Result<()> {
+    pub fn initialize(&mut self, platform_fee: u16, keeper_reward: u16, allowed_mints: Vec<Pubkey>, bump: u8) -> Result<()> {
         require!(
             platform_fee > 0 && platform_fee <= 10_000,
             ConfigErrors::InvalidFeeRange
         );
+        require!(keeper_reward <= 10_000, ConfigErrors::InvalidFeeRange);
         require!(
             allowed_mints.len() <= MAX_MINTS_LENGTH as usize
             && allowed_mints.len() > 0,
@@ -39,7 +42,15 @@ impl<'info> Initialize<'info> {
                 allowed_mints,
                 platform_fee,
                 fee_wallet: self.fee_wallet.key(),
-                bump
+                bump,
+                admin: self.admin.key(),
+                pending_admin: None,
+                keeper_reward,
+                dealer: self.dealer.key(),
+                paused: PauseFlags::default(),
+                moves_paused_at: None,
+                moves_resumed_at: 0,
+                moves_paused_for: 0
             }
         );
         Ok(())
@@ -4,10 +4,12 @@ use anchor_spl::{
     token_interface::{ Mint, TokenInterface, TokenAccount }
 };
 use crate::constants::{
-    CONFIG_SEED, GAME_SEED, MAX_NO_PLAYERS, MAX_WAIT_TIME, MIN_NO_PLAYERS, MIN_WAIT_TIME, PROFILE_SEED
+    CONFIG_SEED, GAME_SEED, MAX_NO_PLAYERS, MINT_CONFIG_SEED, MAX_WAIT_TIME, MIN_NO_PLAYERS, MIN_WAIT_TIME, MOVE_LOG_SEED,
+    PROFILE_SEED
 };
 use crate::errors::GameErrors;
-use crate::state::{Game, Config, Player, Profile};
+use crate::events::GameCreated;
+use crate::state::{Game, Config, LobbySettings, MintConfig, MoveLog, Player, Profile, RuleSet};
 use crate::utils::spl_transfer;
 
 
@@ -36,6 +38,17 @@ pub struct InitializeGame<'info> {
         bump
     )]
     pub game: Account<'info, Game>,
+    #[account(
+        init,
+        payer = signer,
+        space = MoveLog::DISCRIMINATOR.len() + MoveLog::INIT_SPACE,
+        seeds = [
+            &MOVE_LOG_SEED.as_bytes(), 
+            game.key().as_ref()
+            ],
+        bump
+    )]
+    pub move_log: Account<'info, MoveLog>,
         #[account(
         init,
         payer = signer,
@@ -62,6 +75,11 @@ pub struct InitializeGame<'info> {
         bump = config.bump
     )]
     pub config: Account<'info, Config>,
+    #[account(
+        seeds = [&MINT_CONFIG_SEED.as_bytes(), stake_mint.key().as_ref()],
+        bump = mint_config.bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
@@ -73,8 +91,10 @@ impl<'info> InitializeGame<'info> {
     entry_stake: u64,
     no_players: u8,
     wait_time: i64,
+    rules: RuleSet,
     bump: u8
     ) -> Result<()> {
+        require!(!self.config.paused.new_games, GameErrors::Paused);
         let clock = Clock::get()?;
         // check if user has enough balance for stake
         require!(self.user_ata.amount >= entry_stake, 
@@ -86,9 +106,13 @@ impl<'info> InitializeGame<'info> {
         require!(no_players >= MIN_NO_PLAYERS && no_players <= MAX_NO_PLAYERS,
             GameErrors::InvalidNoPlayers
         );
+        // make sure the rules can be dealt for this many players
+        require!(rules.is_valid(no_players), GameErrors::InvalidRuleSet);
         // make sure mint is in allowed list
         require!(self.config.allowed_mints.contains(&self.stake_mint.key()), 
         GameErrors::InvalidMint);
+        // make sure the stake fits the mint's limits
+        require!(self.mint_config.allows_stake(entry_stake), GameErrors::InvalidEntryStake);
 
         // user's player account
         let player_account = Player {
@@ -96,33 +120,89 @@ impl<'info> InitializeGame<'info> {
             username: self.profile.username.to_owned(),
             hand: None,
             card_count: None,
+            score: 0,
             player_index: None,
+            out: false,
+            time_bank: 0,
+            timeouts: 0,
             claimed: false
         };
 
         // update game state
         self.game.set_inner(
             Game {
+                creator: self.signer.key(),
                 owner: self.signer.key(),
                 entry_stake: entry_stake,
                 game_vault: self.game_vault.key(),
                 stake_mint: self.stake_mint.key(),
+                keeper_rewards: 0,
                 no_players: no_players,
                 player_turn: 0,
                 players: vec![player_account],
                 winner: None,
+                winning_team: None,
                 call_card: None,
+                requested_shape: None,
+                pending_pick: 0,
                 draw_pile: None,
+                discard_pile: vec![],
+                reshuffles: 0,
                 wait_time: wait_time, 
+                rules,
+                lobby: LobbySettings::default(), // see `set_lobby_settings`
                 seed: seed,
                 random_seed: None,
+                deck_root: None,
                 delegated: false,
                 started: false,
                 ended: false,
+                round: 0,
+                round_ended: false,
+                awaiting_reveal: false,
                 created_at: clock.unix_timestamp,
+                lobby_expires_at: 0,
                 started_at: None,
                 ended_at: None,
                 last_move_time: None,
+                bump,
+                keepers: vec![],
+                platform_fee: self.mint_config.platform_fee(&self.config),
+                dealing: false,
+                dealer_waiting_since: None,
+                moves_paused_for: self.config.moves_paused_for,
+                moves: 0
+            }
+        );
+
+        emit!(GameCreated {
+            game: self.game.key(),
+            owner: self.signer.key(),
+            seed,
+            stake_mint: self.stake_mint.key(),
+            entry_stake,
+            no_players,
+            wait_time,
+            rules,
+            created_at: clock.unix_timestamp
+        });
+        Ok(())
+    }
+
+    pub fn set_lobby_settings(&mut self, lobby: LobbySettings) -> Result<()> {
+        // make sure the lobby expires, but not before anyone can join
+        require!(lobby.is_valid(), GameErrors::InvalidLobbySettings);
+        self.game.lobby = lobby;
+        self.game.lobby_expires_at = self.game.created_at + lobby.ttl;
+        Ok(())
+    }
+
+    pub fn initialize_move_log(&mut self, bump: u8) -> Result<()> {
+        self.move_log.set_inner(
+            MoveLog {
+                game: self.game.key(),
+                deals: vec![],
+                moves: vec![],
                 bump
             }
         );
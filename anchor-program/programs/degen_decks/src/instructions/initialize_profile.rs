@@ -31,7 +31,8 @@ impl<'info> InitializeProfile<'info> {
             total_won: 0,
             total_lost: 0,
             created_at: clock.unix_timestamp,
-            bump
+            bump,
+            games_played: 0
         });
         Ok(())
     }
@@ -4,10 +4,11 @@ use anchor_spl::{
     token_interface::{ Mint, TokenInterface, TokenAccount }
 };
 use crate::{constants::{
-    CONFIG_SEED, GAME_SEED, PROFILE_SEED
+    CONFIG_SEED, GAME_SEED, MOVE_LOG_SEED, PROFILE_SEED
 }, instruction::ConsumeRandomness};
 use crate::errors::GameErrors;
-use crate::state::{Game, Config, Player, Profile};
+use crate::events::PlayerJoined;
+use crate::state::{Game, Config, MoveLog, Player, Profile};
 use crate::utils::spl_transfer;
 use ephemeral_vrf_sdk::anchor::vrf;
 use ephemeral_vrf_sdk::instructions::{create_request_randomness_ix, RequestRandomnessParams};
@@ -32,11 +33,19 @@ pub struct JoinGame<'info> {
         seeds = [
             &GAME_SEED.as_bytes(), 
             game.seed.to_le_bytes().as_ref(), 
-            game.owner.as_ref()
+            game.creator.as_ref()
             ],
         bump = game.bump
     )]
     pub game: Account<'info, Game>,
+    #[account(
+        seeds = [
+            &MOVE_LOG_SEED.as_bytes(), 
+            game.key().as_ref()
+            ],
+        bump = move_log.bump
+    )]
+    pub move_log: Account<'info, MoveLog>,
     #[account(
         mut,
         associated_token::mint = stake_mint,
@@ -72,10 +81,25 @@ pub struct JoinGame<'info> {
 
 impl<'info> JoinGame<'info> {
     pub fn join_game(&mut self) -> Result<()> {
+        require!(!self.config.paused.joins, GameErrors::Paused);
         require!(
             self.game.players.len() < self.game.no_players as usize,
             GameErrors::PlayersAlreadyComplete
         );
+        require!(
+            !self.game.players.iter().any(|player| player.owner == self.signer.key()),
+            GameErrors::AlreadyJoined
+        );
+        let now = Clock::get()?.unix_timestamp;
+        require!(now < self.game.lobby_expires_at, GameErrors::LobbyExpired);
+        require!(
+            now - self.profile.created_at >= self.game.lobby.min_profile_age,
+            GameErrors::ProfileTooNew
+        );
+        require!(
+            self.profile.games_played >= self.game.lobby.min_games_played,
+            GameErrors::NotEnoughGamesPlayed
+        );
         require!(
             self.user_ata.amount >= self.game.entry_stake,
             GameErrors::InsufficientFunds
@@ -92,9 +116,22 @@ impl<'info> JoinGame<'info> {
             hand: None,
             player_index: None,
             card_count: None,
+            score: 0,
+            out: false,
+            time_bank: 0,
+            timeouts: 0,
             claimed: false
         };
         self.game.players.push(player_account);
+        // a full hidden hands table waits for the dealer's deck
+        self.game.track_dealer(now);
+
+        emit!(PlayerJoined {
+            game: self.game.key(),
+            player: self.signer.key(),
+            username: self.profile.username.to_owned(),
+            players: self.game.players.len() as u8
+        });
         Ok(())
     }
 
@@ -124,8 +161,21 @@ impl<'info> JoinGame<'info> {
                             pubkey: self.game.key(),
                             is_signer: false,
                             is_writable: true
+                        },
+                        SerializableAccountMeta {
+                            pubkey: self.move_log.key(),
+                            is_signer: false,
+                            is_writable: true
                         }
                     ]
+                    .into_iter()
+                    // the first deal counts the game for every seat
+                    .chain(self.game.profiles().map(|pubkey| SerializableAccountMeta {
+                        pubkey,
+                        is_signer: false,
+                        is_writable: true
+                    }))
+                    .collect()
                 ),
                 ..Default::default()
             }
@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::constants::CONFIG_SEED;
+use crate::errors::ConfigErrors;
+use crate::state::{Config, PauseFlags};
+
+/// Grows a config created with the first layout, which ended at `bump`, and
+/// sets the signing upgrade authority as its admin. Can only run once.
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: an old config does not deserialize as `Config`, its owner and
+    /// discriminator are checked in the handler
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump,
+        owner = crate::ID
+    )]
+    pub config: UncheckedAccount<'info>,
+    /// CHECK: dealer of hidden hand games
+    pub dealer: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = anchor_lang::solana_program::bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ConfigErrors::NotAuthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+}
+
+/// `Config` as it was first laid out
+#[derive(AnchorDeserialize)]
+struct ConfigV1 {
+    platform_fee: u16,
+    fee_wallet: Pubkey,
+    allowed_mints: Vec<Pubkey>,
+    bump: u8,
+}
+
+impl ConfigV1 {
+    fn migrate(self, admin: Pubkey, dealer: Pubkey) -> Config {
+        Config {
+            platform_fee: self.platform_fee,
+            fee_wallet: self.fee_wallet,
+            allowed_mints: self.allowed_mints,
+            bump: self.bump,
+            admin,
+            pending_admin: None,
+            keeper_reward: 0,
+            dealer,
+            paused: PauseFlags::default(),
+            moves_paused_at: None,
+            moves_resumed_at: 0,
+            moves_paused_for: 0
+        }
+    }
+}
+
+/// Reads a config account in the first layout into the current one
+fn migrate(data: &[u8], admin: Pubkey, dealer: Pubkey) -> Result<Config> {
+    require!(
+        data.len() < Config::DISCRIMINATOR.len() + Config::INIT_SPACE,
+        ConfigErrors::AlreadyMigrated
+    );
+    let mut fields = data
+        .strip_prefix(Config::DISCRIMINATOR)
+        .ok_or(ConfigErrors::InvalidConfig)?;
+    let old = ConfigV1::deserialize(&mut fields).map_err(|_| ConfigErrors::InvalidConfig)?;
+    Ok(old.migrate(admin, dealer))
+}
+
+impl<'info> MigrateConfig<'info> {
+    pub fn migrate_config(&mut self) -> Result<()> {
+        let config = migrate(
+            &self.config.try_borrow_data()?,
+            self.admin.key(),
+            self.dealer.key()
+        )?;
+
+        let space = Config::DISCRIMINATOR.len() + Config::INIT_SPACE;
+        let rent = Rent::get()?.minimum_balance(space);
+        let top_up = rent.saturating_sub(self.config.lamports());
+        if top_up > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.admin.to_account_info(),
+                        to: self.config.to_account_info(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        self.config.to_account_info().resize(space)?;
+
+        let mut data = self.config.try_borrow_mut_data()?;
+        config.try_serialize(&mut &mut data[..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::MAX_MINTS_LENGTH;
+
+    /// A config account as `initialize` wrote it with the first layout
+    fn old_config(allowed_mints: &[Pubkey]) -> Vec<u8> {
+        let mut data = Config::DISCRIMINATOR.to_vec();
+        1_000u16.serialize(&mut data).unwrap();
+        Pubkey::new_from_array([7; 32]).serialize(&mut data).unwrap();
+        allowed_mints.to_vec().serialize(&mut data).unwrap();
+        254u8.serialize(&mut data).unwrap();
+        // sized for the longest mint list, like `init` allocated it
+        data.resize(Config::DISCRIMINATOR.len() + 2 + 32 + 4 + 32 * MAX_MINTS_LENGTH as usize + 1, 0);
+        data
+    }
+
+    #[test]
+    fn an_old_config_keeps_its_fields_and_gets_an_admin() {
+        let mints = [Pubkey::new_from_array([1; 32]), Pubkey::new_from_array([2; 32])];
+        let admin = Pubkey::new_from_array([3; 32]);
+        let dealer = Pubkey::new_from_array([4; 32]);
+
+        let config = migrate(&old_config(&mints), admin, dealer).unwrap();
+        assert_eq!(config.platform_fee, 1_000);
+        assert_eq!(config.fee_wallet, Pubkey::new_from_array([7; 32]));
+        assert_eq!(config.allowed_mints, mints);
+        assert_eq!(config.bump, 254);
+        assert_eq!(config.admin, admin);
+        assert_eq!(config.pending_admin, None);
+        assert_eq!(config.keeper_reward, 0);
+        assert_eq!(config.dealer, dealer);
+        assert_eq!(config.paused, PauseFlags::default());
+
+        // the migrated config fits the grown account and reads back as is
+        let mut data = vec![0; Config::DISCRIMINATOR.len() + Config::INIT_SPACE];
+        config.try_serialize(&mut &mut data[..]).unwrap();
+        let read = Config::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(read.admin, admin);
+        assert_eq!(read.allowed_mints, mints);
+    }
+
+    #[test]
+    fn a_config_is_only_migrated_once() {
+        let config = migrate(&old_config(&[]), Pubkey::default(), Pubkey::default()).unwrap();
+        let mut data = vec![0; Config::DISCRIMINATOR.len() + Config::INIT_SPACE];
+        config.try_serialize(&mut &mut data[..]).unwrap();
+        assert!(migrate(&data, Pubkey::default(), Pubkey::default()).is_err());
+    }
+
+    #[test]
+    fn other_accounts_are_not_migrated() {
+        let mut data = old_config(&[]);
+        data[0] ^= 1;
+        assert!(migrate(&data, Pubkey::default(), Pubkey::default()).is_err());
+    }
+}
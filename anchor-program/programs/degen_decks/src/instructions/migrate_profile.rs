@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::constants::PROFILE_SEED;
+use crate::errors::ProfileErrors;
+use crate::state::Profile;
+
+/// Grows a profile created before `games_played` was added to the current
+/// layout. The new bytes are zeroed, so the count starts at 0.
+#[derive(Accounts)]
+pub struct MigrateProfile<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    /// CHECK: an old profile does not deserialize as `Profile`, its owner
+    /// and discriminator are checked in the handler
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED.as_bytes(), signer.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub profile: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateProfile<'info> {
+    pub fn migrate_profile(&mut self) -> Result<()> {
+        require!(
+            self.profile.try_borrow_data()?.starts_with(Profile::DISCRIMINATOR),
+            ProfileErrors::InvalidProfile
+        );
+
+        let space = Profile::DISCRIMINATOR.len() + Profile::INIT_SPACE;
+        if self.profile.data_len() >= space {
+            return Ok(());
+        }
+
+        let rent = Rent::get()?.minimum_balance(space);
+        let top_up = rent.saturating_sub(self.profile.lamports());
+        if top_up > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.signer.to_account_info(),
+                        to: self.profile.to_account_info(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        self.profile.to_account_info().resize(space)?;
+        Ok(())
+    }
+}
@@ -1,9 +1,24 @@
 pub mod initialize;
 pub use initialize::*;
 
+pub mod migrate_config;
+pub use migrate_config::*;
+
+pub mod update_config;
+pub use update_config::*;
+
+pub mod accept_admin;
+pub use accept_admin::*;
+
+pub mod set_mint_config;
+pub use set_mint_config::*;
+
 pub mod initialize_profile;
 pub use initialize_profile::*;
 
+pub mod migrate_profile;
+pub use migrate_profile::*;
+
 pub mod initialize_game;
 pub use initialize_game::*;
 
@@ -13,6 +28,12 @@ pub use join_game::*;
 pub mod exit_game;
 pub use exit_game::*;
 
+pub mod resign;
+pub use resign::*;
+
+pub mod cancel_expired_game;
+pub use cancel_expired_game::*;
+
 pub mod play_card;
 pub use play_card::*;  
 
@@ -25,14 +46,47 @@ pub use draw_from_pile::*;
 pub mod draw_from_pile_delegate;
 pub use draw_from_pile_delegate::*;
 
+pub mod accept_penalty;
+pub use accept_penalty::*;
+
+pub mod get_legal_moves;
+pub use get_legal_moves::*;
+
+pub mod get_game_view;
+pub use get_game_view::*;
+
 pub mod claim_prize;
 pub use claim_prize::*;
 
+pub mod claim_keeper_reward;
+pub use claim_keeper_reward::*;
+
+pub mod cancel_stalled_game;
+pub use cancel_stalled_game::*;
+
+pub mod close_game;
+pub use close_game::*;
+
 pub mod penalize_opponent;
 pub use penalize_opponent::*;
 
+pub mod crank_timeout;
+pub use crank_timeout::*;
+
 pub mod consume_randomness;
 pub use consume_randomness::*;
 
+pub mod start_next_round;
+pub use start_next_round::*;
+
 pub mod commit_game;
 pub use commit_game::*;
+
+pub mod commit_deck;
+pub use commit_deck::*;
+
+pub mod reveal_call_card;
+pub use reveal_call_card::*;
+
+pub mod reveal_deck;
+pub use reveal_deck::*;
@@ -1,13 +1,24 @@
 use anchor_lang::prelude::*;
+use whot_engine::{Action, Move};
 use crate::{
     constants::{
+        CONFIG_SEED, 
         GAME_SEED, 
+        MOVE_LOG_SEED, 
         PROFILE_SEED
     }, 
     errors::GameErrors, 
+    events::{
+        CardPlayed, 
+        GameEnded, 
+        SpecialEffectTriggered
+    }, 
     state::{
         Card, 
+        CardProof, 
+        Config, 
         Game, 
+        MoveLog, 
         Profile
     }
 };
@@ -30,24 +41,62 @@ pub struct PlayCard<'info> {
         seeds = [
             &GAME_SEED.as_bytes(), 
             game.seed.to_le_bytes().as_ref(), 
-            game.owner.as_ref()
+            game.creator.as_ref()
             ],
         bump = game.bump
     )]
-    game: Account<'info, Game>
+    game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [
+            &MOVE_LOG_SEED.as_bytes(), 
+            game.key().as_ref()
+            ],
+        bump = move_log.bump
+    )]
+    pub move_log: Account<'info, MoveLog>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>
 }
 
 impl<'info> PlayCard<'info> {
-    pub fn play_card(&mut self, card: Card) -> Result<()> {
-        let player = self.game.players.iter().find(|p| p.owner == self.signer.key()).ok_or(GameErrors::PlayerNotFound)?;
-        
-        require!(player.player_index == Some(self.game.player_turn), GameErrors::NotYourTurn);
-        require!(self.game.started == true, GameErrors::GameNotStarted);
-        require!(self.game.ended == false, GameErrors::GameEnded);
+    pub fn play_card(&mut self, card: Card, need: Option<u8>, proof: Option<CardProof>) -> Result<()> {
+        require!(!self.config.paused.moves, GameErrors::Paused);
+        self.game.catch_up_pause(&self.config, &mut self.move_log)?;
+        let seat = self.game.seat_of(&self.signer.key())?;
+        // a face-down card is turned up before it is played
+        if let Some(proof) = proof {
+            self.game.reveal_from_hand(seat, card, &proof)?;
+        }
+        let mv = Move::new(seat, Action::Play { card: card.into(), need });
+        let now = Clock::get()?.unix_timestamp;
+        self.game.apply_move(mv, now)?;
+        self.move_log.record_move(mv, now)?;
 
-        self.game.last_move_time = Some(Clock::get()?.unix_timestamp);
-        self.game.validate_play(&card)?;
-        self.game.handle_call_card()?;
+        let game = self.game.key();
+        emit!(CardPlayed {
+            game,
+            player: self.signer.key(),
+            seat,
+            card,
+            need,
+            cards_left: self.game.hand_len(seat)
+        });
+        if let Some(effect) = self.game.effect_of(card) {
+            emit!(SpecialEffectTriggered {
+                game,
+                seat,
+                effect: effect.into(),
+                requested_shape: self.game.requested_shape,
+                pending_pick: self.game.pending_pick
+            });
+        }
+        if self.game.ended {
+            emit!(GameEnded::new(game, &self.game));
+        }
 
         Ok(())
     }
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use whot_engine::{Action, Move};
+use crate::{
+    constants::{
+        GAME_SEED, 
+        MOVE_LOG_SEED, 
+        PROFILE_SEED
+    }, 
+    events::{
+        GameEnded, 
+        PlayerResigned
+    }, 
+    state::{
+        Game, 
+        MoveLog, 
+        Profile
+    }
+};
+
+
+#[derive(Accounts)]
+pub struct Resign<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        seeds = [
+            &PROFILE_SEED.as_bytes(), 
+            signer.key().as_ref()
+            ],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, Profile>,
+    #[account(
+        mut,
+        seeds = [
+            &GAME_SEED.as_bytes(), 
+            game.seed.to_le_bytes().as_ref(), 
+            game.creator.as_ref()
+            ],
+        bump = game.bump
+    )]
+    game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [
+            &MOVE_LOG_SEED.as_bytes(), 
+            game.key().as_ref()
+            ],
+        bump = move_log.bump
+    )]
+    pub move_log: Account<'info, MoveLog>
+}
+
+impl<'info> Resign<'info> {
+    /// Leave a dealt game, the stake stays in the pot
+    pub fn resign(&mut self) -> Result<()> {
+        let seat = self.game.seat_of(&self.signer.key())?;
+        let mv = Move::new(seat, Action::Resign);
+        let now = Clock::get()?.unix_timestamp;
+        self.game.apply_move(mv, now)?;
+        self.move_log.record_move(mv, now)?;
+
+        let game = self.game.key();
+        emit!(PlayerResigned {
+            game,
+            player: self.signer.key(),
+            seat,
+            players_left: self.game.players.iter().filter(|p| !p.out).count() as u8
+        });
+        if self.game.ended {
+            emit!(GameEnded::new(game, &self.game));
+        }
+
+        Ok(())
+    }
+}
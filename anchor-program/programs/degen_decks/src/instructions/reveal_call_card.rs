@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::{GAME_SEED, MOVE_LOG_SEED}, 
+    errors::GameErrors, 
+    events::CallCardRevealed, 
+    state::{
+        Card, 
+        CardProof, 
+        Game, 
+        MoveLog
+    }
+};
+
+
+#[derive(Accounts)]
+pub struct RevealCallCard<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            &GAME_SEED.as_bytes(), 
+            game.seed.to_le_bytes().as_ref(), 
+            game.creator.as_ref()
+            ],
+        bump = game.bump
+    )]
+    game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [
+            MOVE_LOG_SEED.as_bytes(), 
+            game.key().as_ref()
+            ],
+        bump = move_log.bump
+    )]
+    pub move_log: Account<'info, MoveLog>
+}
+
+impl<'info> RevealCallCard<'info> {
+    pub fn reveal_call_card(&mut self, card: Card, proof: CardProof) -> Result<()> {
+        // only the face-down call card can be turned up here, cards in a
+        // hand are revealed by playing them
+        let call_card: Option<whot_engine::Card> = self.game.call_card.map(Into::into);
+        require!(
+            call_card.and_then(|call_card| call_card.hidden_position()) == Some(proof.position),
+            GameErrors::CardNotHidden
+        );
+        self.game.reveal_card(card, &proof)?;
+        // the seat on turn could not play before the call card was up, its
+        // clock starts now, as it does when the round is dealt face up
+        let now = Clock::get()?.unix_timestamp;
+        self.game.resume_clock(now, &mut self.move_log)?;
+        self.game.track_dealer(now);
+
+        emit!(CallCardRevealed {
+            game: self.game.key(),
+            round: self.game.round,
+            position: proof.position,
+            card
+        });
+        Ok(())
+    }
+}
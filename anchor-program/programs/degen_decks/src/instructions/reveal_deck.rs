@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::{
+        CONFIG_SEED, 
+        GAME_SEED, 
+        MOVE_LOG_SEED
+    }, 
+    errors::GameErrors, 
+    events::{
+        DeckRevealed, 
+        GameEnded
+    }, 
+    state::{
+        Config, 
+        Game, 
+        MoveLog, 
+        PackedCard
+    }
+};
+
+
+#[derive(Accounts)]
+pub struct RevealDeck<'info> {
+    #[account(
+        mut,
+        address = config.dealer @ GameErrors::InvalidAuthority
+    )]
+    pub dealer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            &GAME_SEED.as_bytes(), 
+            game.seed.to_le_bytes().as_ref(), 
+            game.creator.as_ref()
+            ],
+        bump = game.bump
+    )]
+    game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [
+            &MOVE_LOG_SEED.as_bytes(), 
+            game.key().as_ref()
+            ],
+        bump = move_log.bump
+    )]
+    pub move_log: Account<'info, MoveLog>,
+    #[account(
+        seeds = [
+            &CONFIG_SEED.as_bytes()
+        ],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>
+}
+
+impl<'info> RevealDeck<'info> {
+    pub fn reveal_deck(&mut self, secret: [u8; 32], cards: Vec<PackedCard>) -> Result<()> {
+        let deck: Vec<whot_engine::Card> = cards.iter().copied().map(Into::into).collect();
+        let now = Clock::get()?.unix_timestamp;
+        self.game.reveal_deck(secret, &deck, now)?;
+        self.move_log.record_reveal(cards, now)?;
+
+        let game = self.game.key();
+        emit!(DeckRevealed {
+            game,
+            round: self.game.round,
+            secret,
+            card_counts: self.game.players.iter().map(|p| p.card_count.unwrap_or(0)).collect(),
+            revealed_at: now
+        });
+        if self.game.ended {
+            emit!(GameEnded::new(game, &self.game));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use crate::constants::{CONFIG_SEED, MAX_STAKE_TIERS, MINT_CONFIG_SEED};
+use crate::errors::ConfigErrors;
+use crate::events::MintConfigUpdated;
+use crate::state::{Config, MintConfig};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+#[derive(Accounts)]
+pub struct SetMintConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+        has_one = admin @ ConfigErrors::NotAuthorized
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        constraint = config.allowed_mints.contains(&stake_mint.key()) @ ConfigErrors::MintNotAllowed
+    )]
+    pub stake_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = MintConfig::DISCRIMINATOR.len() + MintConfig::INIT_SPACE,
+        seeds = [&MINT_CONFIG_SEED.as_bytes(), stake_mint.key().as_ref()],
+        bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetMintConfig<'info> {
+    /// Set the stake limits of an allowed mint, `stake_tiers` left empty
+    /// allows any stake between `min_stake` and `max_stake`
+    pub fn set_mint_config(
+        &mut self,
+        min_stake: u64,
+        max_stake: u64,
+        stake_tiers: Vec<u64>,
+        platform_fee: Option<u16>,
+        bump: u8
+    ) -> Result<()> {
+        require!(
+            min_stake > 0
+            && min_stake <= max_stake
+            && stake_tiers.len() <= MAX_STAKE_TIERS as usize
+            && stake_tiers.iter().all(|tier| (min_stake..=max_stake).contains(tier)),
+            ConfigErrors::InvalidStakeRange
+        );
+        require!(
+            !platform_fee.is_some_and(|fee| fee == 0 || fee > 10_000),
+            ConfigErrors::InvalidFeeRange
+        );
+
+        self.mint_config.set_inner(MintConfig {
+            mint: self.stake_mint.key(),
+            min_stake,
+            max_stake,
+            stake_tiers,
+            platform_fee,
+            bump
+        });
+
+        emit!(MintConfigUpdated {
+            mint: self.mint_config.mint,
+            min_stake,
+            max_stake,
+            stake_tiers: self.mint_config.stake_tiers.clone(),
+            platform_fee
+        });
+        Ok(())
+    }
+}
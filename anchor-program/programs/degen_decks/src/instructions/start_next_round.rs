@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use crate::{constants::{
+    GAME_SEED, MOVE_LOG_SEED, PROFILE_SEED
+}, instruction::ConsumeRandomness};
+use crate::errors::GameErrors;
+use crate::state::{Game, MoveLog, Profile};
+use ephemeral_vrf_sdk::anchor::vrf;
+use ephemeral_vrf_sdk::instructions::{create_request_randomness_ix, RequestRandomnessParams};
+use ephemeral_vrf_sdk::types::SerializableAccountMeta;
+
+
+#[vrf]
+#[derive(Accounts)]
+pub struct StartNextRound<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        seeds = [
+            &PROFILE_SEED.as_bytes(), 
+            signer.key().as_ref()
+            ],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, Profile>,
+    #[account(
+        mut,
+        seeds = [
+            &GAME_SEED.as_bytes(), 
+            game.seed.to_le_bytes().as_ref(), 
+            game.creator.as_ref()
+            ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        seeds = [
+            &MOVE_LOG_SEED.as_bytes(), 
+            game.key().as_ref()
+            ],
+        bump = move_log.bump
+    )]
+    pub move_log: Account<'info, MoveLog>,
+    /// CHECK: The oracle queue
+    #[account(mut, address = ephemeral_vrf_sdk::consts::DEFAULT_QUEUE)]
+    pub oracle_queue: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> StartNextRound<'info> {
+    pub fn start_next_round(&mut self) -> Result<()> {
+        // any seated player can ask for the next deal
+        require!(
+            self.game.players.iter().any(|player| player.owner == self.signer.key()),
+            GameErrors::PlayerNotFound
+        );
+        require!(!self.game.ended, GameErrors::GameEnded);
+        require!(self.game.round_ended, GameErrors::RoundNotEnded);
+        require!(!self.game.rules.hidden_hands, GameErrors::DealerDeals);
+        // one request per round, the callback clears it
+        require!(!self.game.dealing, GameErrors::DealInProgress);
+        self.game.dealing = true;
+        Ok(())
+    }
+
+    pub fn request_randomness(&mut self) -> Result<()> {
+        // request randomness for the next deal, the round counter keeps
+        // every request unique
+        let mut caller_seed = [5; 32];
+        caller_seed[0] = self.game.round;
+        let ix = create_request_randomness_ix(
+            RequestRandomnessParams {
+                payer: self.signer.key(),
+                oracle_queue: self.oracle_queue.key(),
+                callback_program_id: crate::ID,
+                callback_discriminator: ConsumeRandomness::DISCRIMINATOR.to_vec(),
+                caller_seed,
+                accounts_metas: Some(
+                    vec![
+                        SerializableAccountMeta {
+                            pubkey: self.game.key(),
+                            is_signer: false,
+                            is_writable: true
+                        },
+                        SerializableAccountMeta {
+                            pubkey: self.move_log.key(),
+                            is_signer: false,
+                            is_writable: true
+                        }
+                    ]
+                ),
+                ..Default::default()
+            }
+        );
+        self.invoke_signed_vrf(&self.signer.to_account_info(), &ix)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+use crate::constants::{CONFIG_SEED, MAX_MINTS_LENGTH};
+use crate::errors::ConfigErrors;
+use crate::events::{AdminProposed, ConfigUpdated, DealerChanged, PauseChanged};
+use crate::state::{Config, PauseFlags};
+use anchor_lang::prelude::*;
+
+/// Config edits, signed by the admin stored in `Config`
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+        has_one = admin @ ConfigErrors::NotAuthorized
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> UpdateConfig<'info> {
+    pub fn update_fee(&mut self, platform_fee: u16, keeper_reward: u16) -> Result<()> {
+        require!(
+            platform_fee > 0 && platform_fee <= 10_000,
+            ConfigErrors::InvalidFeeRange
+        );
+        require!(keeper_reward <= 10_000, ConfigErrors::InvalidFeeRange);
+        self.config.platform_fee = platform_fee;
+        self.config.keeper_reward = keeper_reward;
+        self.emit_updated();
+        Ok(())
+    }
+
+    pub fn add_allowed_mint(&mut self, mint: Pubkey) -> Result<()> {
+        require!(
+            !self.config.allowed_mints.contains(&mint),
+            ConfigErrors::MintAlreadyAllowed
+        );
+        require!(
+            self.config.allowed_mints.len() < MAX_MINTS_LENGTH as usize,
+            ConfigErrors::InvalidMintsRange
+        );
+        self.config.allowed_mints.push(mint);
+        self.emit_updated();
+        Ok(())
+    }
+
+    /// Games already staked in the mint play on, only new games are refused
+    pub fn remove_allowed_mint(&mut self, mint: Pubkey) -> Result<()> {
+        let index = self
+            .config
+            .allowed_mints
+            .iter()
+            .position(|allowed| *allowed == mint)
+            .ok_or(ConfigErrors::MintNotAllowed)?;
+        require!(
+            self.config.allowed_mints.len() > 1,
+            ConfigErrors::InvalidMintsRange
+        );
+        self.config.allowed_mints.remove(index);
+        self.emit_updated();
+        Ok(())
+    }
+
+    pub fn set_fee_wallet(&mut self, fee_wallet: Pubkey) -> Result<()> {
+        self.config.fee_wallet = fee_wallet;
+        self.emit_updated();
+        Ok(())
+    }
+
+    /// Decks the old dealer committed to can only be revealed with its
+    /// secret, games it leaves waiting are called off after `DEALER_TIMEOUT`
+    pub fn set_dealer(&mut self, dealer: Pubkey) -> Result<()> {
+        let previous_dealer = self.config.dealer;
+        self.config.dealer = dealer;
+        emit!(DealerChanged {
+            previous_dealer,
+            dealer
+        });
+        Ok(())
+    }
+
+    /// Turn clocks stop while moves are paused, each game catches up on
+    /// its next move, see `Game::catch_up_pause`
+    pub fn set_paused(&mut self, paused: PauseFlags) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        match (self.config.moves_paused_at, paused.moves) {
+            (None, true) => self.config.moves_paused_at = Some(now),
+            (Some(paused_at), false) => {
+                self.config.moves_paused_at = None;
+                self.config.moves_resumed_at = now;
+                self.config.moves_paused_for += now - paused_at;
+            }
+            _ => {}
+        }
+        self.config.paused = paused;
+        emit!(PauseChanged {
+            admin: self.admin.key(),
+            paused
+        });
+        Ok(())
+    }
+
+    /// First step of the admin handover, `new_admin` takes over once it
+    /// signs `accept_admin`. Proposing again replaces the pending admin.
+    pub fn transfer_admin(&mut self, new_admin: Pubkey) -> Result<()> {
+        self.config.pending_admin = Some(new_admin);
+        emit!(AdminProposed {
+            admin: self.admin.key(),
+            pending_admin: new_admin
+        });
+        Ok(())
+    }
+
+    fn emit_updated(&self) {
+        emit!(ConfigUpdated {
+            admin: self.config.admin,
+            platform_fee: self.config.platform_fee,
+            keeper_reward: self.config.keeper_reward,
+            fee_wallet: self.config.fee_wallet,
+            allowed_mints: self.config.allowed_mints.clone()
+        });
+    }
+}
@@ -9,7 +9,7 @@ pub mod events;
 pub mod utils;
 
 pub use instructions::*;
-use crate::state::Card;
+use crate::state::{Card, CardProof, GameView, LegalMoves, LobbySettings, PackedCard, PauseFlags, RuleSet};
 
 
 
@@ -20,28 +20,84 @@ declare_id!("3yCuDBRGcJfmVLJFr7USp9eqF4hYEKLfwqbkf1xqnFXh");
 #[program]
 pub mod degen_decks {
     use super::*;
-    pub fn initialize(ctx: Context<Initialize>, platform_fee: u16, allow_mints: Vec<Pubkey>) -> Result<()> {
-        ctx.accounts.initialize(platform_fee, allow_mints, ctx.bumps.config)
+    pub fn initialize(ctx: Context<Initialize>, platform_fee: u16, keeper_reward: u16, allow_mints: Vec<Pubkey>) -> Result<()> {
+        ctx.accounts.initialize(platform_fee, keeper_reward, allow_mints, ctx.bumps.config)
+    }
+
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        ctx.accounts.migrate_config()
+    }
+
+    pub fn update_fee(ctx: Context<UpdateConfig>, platform_fee: u16, keeper_reward: u16) -> Result<()> {
+        ctx.accounts.update_fee(platform_fee, keeper_reward)
+    }
+
+    pub fn add_allowed_mint(ctx: Context<UpdateConfig>, mint: Pubkey) -> Result<()> {
+        ctx.accounts.add_allowed_mint(mint)
+    }
+
+    pub fn remove_allowed_mint(ctx: Context<UpdateConfig>, mint: Pubkey) -> Result<()> {
+        ctx.accounts.remove_allowed_mint(mint)
+    }
+
+    pub fn set_fee_wallet(ctx: Context<UpdateConfig>, fee_wallet: Pubkey) -> Result<()> {
+        ctx.accounts.set_fee_wallet(fee_wallet)
+    }
+
+    pub fn set_dealer(ctx: Context<UpdateConfig>, dealer: Pubkey) -> Result<()> {
+        ctx.accounts.set_dealer(dealer)
+    }
+
+    pub fn set_paused(ctx: Context<UpdateConfig>, paused: PauseFlags) -> Result<()> {
+        ctx.accounts.set_paused(paused)
+    }
+
+    pub fn set_mint_config(
+        ctx: Context<SetMintConfig>,
+        min_stake: u64,
+        max_stake: u64,
+        stake_tiers: Vec<u64>,
+        platform_fee: Option<u16>
+    ) -> Result<()> {
+        ctx.accounts.set_mint_config(min_stake, max_stake, stake_tiers, platform_fee, ctx.bumps.mint_config)
+    }
+
+    pub fn transfer_admin(ctx: Context<UpdateConfig>, new_admin: Pubkey) -> Result<()> {
+        ctx.accounts.transfer_admin(new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        ctx.accounts.accept_admin()
     }
 
     pub fn initialize_profile(ctx: Context<InitializeProfile>, username: String) -> Result<()> {
         ctx.accounts.initialize_profile(username, ctx.bumps.profile)
     }
 
+    pub fn migrate_profile(ctx: Context<MigrateProfile>) -> Result<()> {
+        ctx.accounts.migrate_profile()
+    }
+
     pub fn initialize_game(ctx: Context<InitializeGame>, 
         seed: u64,
         entry_stake: u64,
         no_players: u8,
-        wait_time: i64
+        wait_time: i64,
+        rules: RuleSet,
+        lobby: LobbySettings
     ) -> Result<()> {
-        ctx.accounts.initialize_game(seed, entry_stake, no_players, wait_time, ctx.bumps.game)?;
+        ctx.accounts.initialize_game(seed, entry_stake, no_players, wait_time, rules, ctx.bumps.game)?;
+        ctx.accounts.set_lobby_settings(lobby)?;
+        ctx.accounts.initialize_move_log(ctx.bumps.move_log)?;
         ctx.accounts.deposit_stake(entry_stake)
     }
 
     pub fn join_game(ctx: Context<JoinGame>) -> Result<()> {
         ctx.accounts.join_game()?;
         ctx.accounts.deposit_stake()?;
-        if ctx.accounts.game.players.len() == ctx.accounts.game.no_players as usize {
+        // hidden hand games are dealt once the dealer commits to a deck
+        let game = &ctx.accounts.game;
+        if game.players.len() == game.no_players as usize && !game.rules.hidden_hands {
             ctx.accounts.request_randomness()?;
         }
         Ok(())
@@ -49,15 +105,27 @@ pub mod degen_decks {
 
     pub fn exit_game(ctx: Context<ExitGame>) -> Result<()> {
         ctx.accounts.exit_game()?;
-        ctx.accounts.withdraw_stake()
+        ctx.accounts.withdraw_stake()?;
+        if ctx.accounts.game.players.is_empty() {
+            ctx.accounts.close_lobby()?;
+        }
+        Ok(())
     }
 
-    pub fn play_card(ctx: Context<PlayCard>, card: Card) -> Result<()> {
-        ctx.accounts.play_card(card)
+    pub fn resign(ctx: Context<Resign>) -> Result<()> {
+        ctx.accounts.resign()
     }
 
-    pub fn play_card_delegate(ctx: Context<PlayCardDelegate>, card: Card) -> Result<()> {
-        ctx.accounts.play_card_delegate(card)
+    pub fn cancel_expired_game<'info>(ctx: Context<'_, '_, 'info, 'info, CancelExpiredGame<'info>>) -> Result<()> {
+        ctx.accounts.cancel_expired_game(ctx.remaining_accounts)
+    }
+
+    pub fn play_card(ctx: Context<PlayCard>, card: Card, need: Option<u8>, proof: Option<CardProof>) -> Result<()> {
+        ctx.accounts.play_card(card, need, proof)
+    }
+
+    pub fn play_card_delegate(ctx: Context<PlayCardDelegate>, card: Card, need: Option<u8>, proof: Option<CardProof>) -> Result<()> {
+        ctx.accounts.play_card_delegate(card, need, proof)
     }
 
     pub fn draw_from_pile(ctx: Context<DrawFromPile>) -> Result<()> {
@@ -68,21 +136,69 @@ pub mod degen_decks {
         ctx.accounts.draw_from_pile_delegate()
     }
 
+    pub fn accept_penalty(ctx: Context<AcceptPenalty>) -> Result<()> {
+        ctx.accounts.accept_penalty()
+    }
+
     pub fn penalize_opponent(ctx: Context<PenalizeOpponent>) -> Result<()> {
         ctx.accounts.penalize_opponent()
     }
 
+    pub fn crank_timeout(ctx: Context<CrankTimeout>) -> Result<()> {
+        ctx.accounts.crank_timeout()?;
+        ctx.accounts.reward_keeper()
+    }
+
+    pub fn get_legal_moves(ctx: Context<GetLegalMoves>, seat: u8) -> Result<LegalMoves> {
+        ctx.accounts.get_legal_moves(seat)
+    }
+
+    pub fn get_game_view(ctx: Context<GetGameView>) -> Result<GameView> {
+        ctx.accounts.get_game_view()
+    }
+
     pub fn claim_prize(ctx: Context<ClaimPrize>) -> Result<()> {
         ctx.accounts.claim_prize()
     }
 
-    pub fn consume_randomness(ctx: Context<ConsumeRandomness>, randomness: [u8; 32]) -> Result<()> {
-        ctx.accounts.consume_randomness(randomness)
+    pub fn cancel_stalled_game(ctx: Context<CancelStalledGame>) -> Result<()> {
+        ctx.accounts.cancel_stalled_game()
+    }
+
+    pub fn claim_keeper_reward(ctx: Context<ClaimKeeperReward>) -> Result<()> {
+        ctx.accounts.claim_keeper_reward()
+    }
+
+    pub fn close_game(ctx: Context<CloseGame>) -> Result<()> {
+        ctx.accounts.sweep_dust()?;
+        ctx.accounts.close_vault()
+    }
+
+    pub fn consume_randomness<'info>(ctx: Context<'_, '_, 'info, 'info, ConsumeRandomness<'info>>, randomness: [u8; 32]) -> Result<()> {
+        ctx.accounts.consume_randomness(randomness, ctx.remaining_accounts)
+    }
+
+    pub fn start_next_round(ctx: Context<StartNextRound>) -> Result<()> {
+        ctx.accounts.start_next_round()?;
+        ctx.accounts.request_randomness()
     }
 
     pub fn commit_game(ctx: Context<CommitGame>) -> Result<()> {
         ctx.accounts.commit_game()
     }
 
+    pub fn commit_deck(ctx: Context<CommitDeck>, deck_root: [u8; 32]) -> Result<()> {
+        ctx.accounts.commit_deck(deck_root)?;
+        ctx.accounts.request_randomness()
+    }
+
+    pub fn reveal_call_card(ctx: Context<RevealCallCard>, card: Card, proof: CardProof) -> Result<()> {
+        ctx.accounts.reveal_call_card(card, proof)
+    }
+
+    pub fn reveal_deck(ctx: Context<RevealDeck>, secret: [u8; 32], cards: Vec<PackedCard>) -> Result<()> {
+        ctx.accounts.reveal_deck(secret, cards)
+    }
+
 
 }
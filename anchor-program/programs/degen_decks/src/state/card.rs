@@ -10,4 +10,45 @@ impl Default for Card {
     fn default() -> Self {
         Card {id: 0, card_number: 0}
     }
-}
\ No newline at end of file
+}
+
+impl From<Card> for whot_engine::Card {
+    fn from(card: Card) -> Self {
+        whot_engine::Card::new(card.id, card.card_number)
+    }
+}
+
+impl From<whot_engine::Card> for Card {
+    fn from(card: whot_engine::Card) -> Self {
+        Card {id: card.id, card_number: card.card_number}
+    }
+}
+
+/// One byte storage encoding of a card used for hands and piles,
+/// half the size of the two byte `Card`
+#[derive(InitSpace, AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, PartialEq)]
+pub struct PackedCard(pub u8);
+
+impl From<whot_engine::Card> for PackedCard {
+    fn from(card: whot_engine::Card) -> Self {
+        PackedCard(card.pack())
+    }
+}
+
+impl From<PackedCard> for whot_engine::Card {
+    fn from(card: PackedCard) -> Self {
+        whot_engine::Card::from_packed(card.0)
+    }
+}
+
+impl From<Card> for PackedCard {
+    fn from(card: Card) -> Self {
+        whot_engine::Card::from(card).into()
+    }
+}
+
+impl From<PackedCard> for Card {
+    fn from(card: PackedCard) -> Self {
+        whot_engine::Card::from(card).into()
+    }
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+use crate::state::Card;
+
+/// Opening of a face-down card in a hidden hand game, handed to the
+/// player privately by the dealer. See `whot_engine::Opening`.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug, PartialEq)]
+pub struct CardProof {
+    pub position: u8,
+    pub salt: [u8; 32],
+    pub proof: Vec<[u8; 32]>
+}
+
+impl CardProof {
+    pub fn opening(&self, card: Card) -> whot_engine::Opening {
+        whot_engine::Opening {
+            position: self.position,
+            card: card.into(),
+            salt: self.salt,
+            proof: self.proof.clone()
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 
 use crate::constants::MAX_MINTS_LENGTH;
+use crate::state::PauseFlags;
 
+/// Fields after `bump` were appended to the first layout, configs created
+/// before them are grown with `migrate_config`.
 #[account]
 #[derive(InitSpace)]
 pub struct Config {
@@ -9,5 +12,13 @@ pub struct Config {
     pub fee_wallet: Pubkey,
     #[max_len(MAX_MINTS_LENGTH)]
     pub allowed_mints: Vec<Pubkey>,
-    pub bump: u8
-}
\ No newline at end of file
+    pub bump: u8,
+    pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>, // proposed by `transfer_admin`, takes over on `accept_admin`
+    pub keeper_reward: u16, // basis points of the entry stake paid per `crank_timeout`, out of the fee
+    pub dealer: Pubkey, // commits to and reveals the deck of hidden hand games
+    pub paused: PauseFlags,
+    pub moves_paused_at: Option<i64>,
+    pub moves_resumed_at: i64,
+    pub moves_paused_for: i64, // seconds moves were paused, summed over every pause
+}
@@ -1,367 +1,382 @@
 use crate::errors::GameErrors;
-use crate::state::{Card, Player};
+use crate::constants::{CLOSE_GRACE_PERIOD, DEALER_TIMEOUT, MAX_KEEPERS, PROFILE_SEED};
+use crate::state::{Card, CardProof, Config, KeeperReward, LegalMoves, LobbySettings, MoveLog, PackedCard, Player, RuleSet};
+use crate::utils::SolanaHasher;
 use anchor_lang::prelude::*;
+use whot_engine::{GameState, Move, PlayerState};
 
 #[account]
 #[derive(InitSpace)]
 pub struct Game {
+    pub creator: Pubkey, // PDA seed, fixed even when the owner changes
     pub owner: Pubkey,
     pub entry_stake: u64,
     pub game_vault: Pubkey,
     pub stake_mint: Pubkey,
+    pub keeper_rewards: u64, // earned by keepers with `crank_timeout`, still to come off the fee
     pub no_players: u8,
     pub player_turn: u8,
     #[max_len(5)]
     pub players: Vec<Player>,
     pub winner: Option<Pubkey>,
+    pub winning_team: Option<u8>,
     pub call_card: Option<Card>,
+    pub requested_shape: Option<u8>,
+    pub pending_pick: u8,
     #[max_len(54)]
-    pub draw_pile: Option<Vec<Card>>,
+    pub draw_pile: Option<Vec<PackedCard>>,
+    #[max_len(54)]
+    pub discard_pile: Vec<PackedCard>,
+    pub reshuffles: u8,
     pub wait_time: i64,
+    pub rules: RuleSet,
+    pub lobby: LobbySettings,
     pub seed: u64,
     pub random_seed: Option<u64>,
+    pub deck_root: Option<[u8; 32]>, // dealer's deck commitment for hidden hands
     pub delegated: bool,
     pub started: bool,
     pub ended: bool,
+    pub round: u8,
+    pub round_ended: bool,
+    pub awaiting_reveal: bool,
     pub created_at: i64,
+    pub lobby_expires_at: i64, // lobby can be cancelled and refunded after this
     pub started_at: Option<i64>,
     pub ended_at: Option<i64>,
     pub last_move_time: Option<i64>,
     pub bump: u8,
+    #[max_len(MAX_KEEPERS)]
+    pub keepers: Vec<KeeperReward>, // rewards not yet claimed
+    pub platform_fee: u16, // basis points, fixed when the game is created
+    pub dealing: bool, // next round's randomness requested, not yet consumed
+    pub dealer_waiting_since: Option<i64>, // see `DEALER_TIMEOUT`
+    pub moves_paused_for: i64, // `Config::moves_paused_for` the turn clock has caught up on
+    pub moves: u16, // moves played, see `whot_engine::MAX_MOVES`
 }
 
 impl Game {
-    pub fn handle_call_card(&mut self) -> Result<()> {
-        let call_card = self.call_card.as_ref().ok_or(GameErrors::NoCallCard)?;
-
-        // update last move time
-        self.last_move_time = Some(Clock::get().unwrap().unix_timestamp);
-        if call_card.card_number == 1 {
-            self.handle_hold_on()
-        } else if call_card.card_number == 2 {
-            self.handle_pick_2()
-        } else if call_card.card_number == 5 {
-            self.handle_pick_3()
-        } else if call_card.card_number == 8 {
-            self.handle_suspension()
-        } else if call_card.card_number == 14 {
-            self.handle_general_market()
-        } else if call_card.card_number == 20 {
-            self.handle_need()
-        } else {
-            self.handle_neutral_play()
+    /// Rules engine view of this game
+    pub fn state(&self) -> GameState {
+        GameState {
+            no_players: self.no_players,
+            player_turn: self.player_turn,
+            players: self
+                .players
+                .iter()
+                .map(|player| PlayerState {
+                    hand: player
+                        .hand
+                        .iter()
+                        .flatten()
+                        .map(|card| (*card).into())
+                        .collect(),
+                    card_count: player.card_count,
+                    player_index: player.player_index,
+                    score: player.score,
+                    out: player.out,
+                    time_bank: player.time_bank,
+                    timeouts: player.timeouts,
+                })
+                .collect(),
+            winner: self
+                .winner
+                .and_then(|winner| self.players.iter().position(|p| p.owner == winner))
+                .map(|index| (index + 1) as u8),
+            winning_team: self.winning_team,
+            call_card: self.call_card.map(Into::into),
+            requested_shape: self.requested_shape,
+            pending_pick: self.pending_pick,
+            draw_pile: self
+                .draw_pile
+                .iter()
+                .flatten()
+                .map(|card| (*card).into())
+                .collect(),
+            discard_pile: self.discard_pile.iter().map(|card| (*card).into()).collect(),
+            reshuffles: self.reshuffles,
+            wait_time: self.wait_time,
+            rules: self.rules.into(),
+            random_seed: self.random_seed,
+            started: self.started,
+            ended: self.ended,
+            round: self.round,
+            round_ended: self.round_ended,
+            awaiting_reveal: self.awaiting_reveal,
+            started_at: self.started_at,
+            ended_at: self.ended_at,
+            last_move_time: self.last_move_time,
+            moves: self.moves,
         }
     }
 
-    pub fn validate_play(&mut self, card: &Card) -> Result<()> {
-        let call_card = self.call_card.as_ref().ok_or(GameErrors::NoCallCard)?;
-        // get current player
-        let player_index = (self.player_turn - 1) as usize;
-        let player = &mut self.players[player_index];
-
-        // check if the card is valid
-        // if the call card ID is 1 and user is curent player, they can
-        // play any card
-        if card.id == call_card.id || card.card_number == call_card.card_number {
-            // remove card from current player's hand
-            if let Some(ref mut hand) = player.hand {
-                hand.retain(|c| c != card);
-                // update call card
-                self.call_card = Some(card.clone());
-                return Ok(());
+    /// Write a rules engine state back into this game
+    pub fn set_state(&mut self, state: GameState) {
+        for (player, player_state) in self.players.iter_mut().zip(state.players) {
+            if state.started {
+                player.hand = Some(player_state.hand.into_iter().map(Into::into).collect());
             }
-            return err!(GameErrors::CannotPlayCard);
-        } else {
-            return err!(GameErrors::CannotPlayCard);
+            player.card_count = player_state.card_count;
+            player.player_index = player_state.player_index;
+            player.score = player_state.score;
+            player.out = player_state.out;
+            player.time_bank = player_state.time_bank;
+            player.timeouts = player_state.timeouts;
         }
-    }
 
-    pub fn check_winner(&mut self) -> Result<()> {
-        if self.ended {
-            return err!(GameErrors::GameEnded);
+        self.player_turn = state.player_turn;
+        self.winner = state
+            .winner
+            .and_then(|seat| self.players.get(seat as usize - 1))
+            .map(|player| player.owner);
+        self.winning_team = state.winning_team;
+        self.call_card = state.call_card.map(Into::into);
+        self.requested_shape = state.requested_shape;
+        self.pending_pick = state.pending_pick;
+        if state.started {
+            self.draw_pile = Some(state.draw_pile.into_iter().map(Into::into).collect());
         }
-        // check if player hand is empty or market has finished
-        let player_index = (self.player_turn - 1) as usize;
-        let player = &mut self.players[player_index];
-
-        // first check if player hand is empty
-        if let Some(ref mut hand) = player.hand {
-            if hand.is_empty() {
-                self.handle_checkup();
-                return Ok(());
-            }
-        }
-
-        // second check if market has finished
-        if let Some(ref mut draw_pile) = self.draw_pile {
-            if draw_pile.is_empty() {
-                self.handle_market_finish();
-                return Ok(());
-            }
-        }
-
-        return Ok(());
+        self.discard_pile = state.discard_pile.into_iter().map(Into::into).collect();
+        self.reshuffles = state.reshuffles;
+        self.random_seed = state.random_seed;
+        self.started = state.started;
+        self.ended = state.ended;
+        self.round = state.round;
+        self.round_ended = state.round_ended;
+        self.awaiting_reveal = state.awaiting_reveal;
+        self.started_at = state.started_at;
+        self.ended_at = state.ended_at;
+        self.last_move_time = state.last_move_time;
+        self.moves = state.moves;
     }
 
-    pub fn next_turn(&mut self, step: u8) {
-        self.player_turn = ((self.player_turn - 1 + step) % self.no_players) + 1;
+    /// Deal a new round from the VRF randomness
+    pub fn deal(&mut self, random_seed: u64, now: i64) -> Result<()> {
+        let state = whot_engine::deal_with::<SolanaHasher>(self.state(), random_seed, now)
+            .map_err(GameErrors::from)?;
+        self.set_state(state);
+        self.track_dealer(now);
+        Ok(())
     }
 
-    pub fn handle_draw_from_pile(&mut self) -> Result<()> {
-        if let Some(ref mut draw_pile) = self.draw_pile {
-            // remove top card from draw pile
-            let card = draw_pile.pop().unwrap();
-            // get current player
-            let player_index = (self.player_turn - 1) as usize;
-            let player = &mut self.players[player_index];
-            // add to current player's hand
-            if let Some(ref mut hand) = player.hand {
-                hand.push(card);
-            }
-            self.check_winner()?;
-            if self.ended {
-                return Ok(());
-            }
-            self.next_turn(1);
-            return Ok(());
-        } else {
-            return err!(GameErrors::NoDrawPile);
-        }
+    pub fn apply_move(&mut self, mv: Move, now: i64) -> Result<()> {
+        let state = whot_engine::apply_move_with::<SolanaHasher>(self.state(), mv, now)
+            .map_err(GameErrors::from)?;
+        self.set_state(state);
+        self.track_dealer(now);
+        Ok(())
     }
 
-    pub fn handle_neutral_play(&mut self) -> Result<()> {
-        self.check_winner()?;
-        if self.ended {
+    /// Push the turn clock back by the time moves were paused since it last
+    /// caught up, never past the end of the last pause for a turn that
+    /// started while moves were paused. The restart is logged so the
+    /// replay runs the same clock.
+    pub fn catch_up_pause(&mut self, config: &Config, move_log: &mut MoveLog) -> Result<()> {
+        let paused_for = config.moves_paused_for - self.moves_paused_for;
+        self.moves_paused_for = config.moves_paused_for;
+        let Some(last_move) = self.last_move_time.filter(|_| paused_for > 0) else {
             return Ok(());
+        };
+        let restart = (last_move + paused_for).min(config.moves_resumed_at.max(last_move));
+        if restart > last_move {
+            self.resume_clock(restart, move_log)?;
         }
-        self.next_turn(1);
         Ok(())
     }
 
-    pub fn handle_pick_2(&mut self) -> Result<()> {
-        let player_to_pick_index = (self.player_turn % self.no_players) as usize;
-
-        if let Some(ref mut draw_pile) = self.draw_pile {
-            // remove 2 cards from draw pile
-            // add to next player's hand
-            let player_to_pick = self.players.get_mut(player_to_pick_index).unwrap();
-            if let Some(ref mut hand) = player_to_pick.hand {
-                if draw_pile.len() > 2 {
-                    for _i in 0..2 {
-                        let card = draw_pile.pop().unwrap();
-                        hand.push(card);
-                    }
-                } else {
-                    for _i in 0..draw_pile.len() {
-                        let card = draw_pile.pop().unwrap();
-                        hand.push(card);
-                    }
-                }
-            }
-        } else {
-            return err!(GameErrors::NoDrawPile);
-        }
-        self.check_winner()?;
-        if self.ended {
-            return Ok(());
-        }
-        self.next_turn(2);
-        return Ok(());
+    /// Restart the turn clock at `now`, see `whot_engine::Action::ResumeClock`
+    pub fn resume_clock(&mut self, now: i64, move_log: &mut MoveLog) -> Result<()> {
+        let mv = Move::new(self.player_turn, whot_engine::Action::ResumeClock);
+        self.apply_move(mv, now)?;
+        move_log.record_move(mv, now)
     }
 
-    pub fn handle_pick_3(&mut self) -> Result<()> {
-        let player_to_pick_index = (self.player_turn % self.no_players) as usize;
-
-        if let Some(ref mut draw_pile) = self.draw_pile {
-            // remove 3 cards from draw pile
-            // add to next player's hand
-            let player_to_pick = self.players.get_mut(player_to_pick_index).unwrap();
-            if let Some(ref mut hand) = player_to_pick.hand {
-                if draw_pile.len() > 3 {
-                    for _i in 0..3 {
-                        let card = draw_pile.pop().unwrap();
-                        hand.push(card);
-                    }
-                } else {
-                    for _i in 0..draw_pile.len() {
-                        let card = draw_pile.pop().unwrap();
-                        hand.push(card);
-                    }
-                }
-            }
-        } else {
-            return err!(GameErrors::NoDrawPile);
+    /// Whether a hidden hands table can only go on once the dealer commits
+    /// to a deck, reveals it or opens the call card
+    pub fn waiting_for_dealer(&self) -> bool {
+        if !self.rules.hidden_hands || self.ended || self.players.len() != self.no_players as usize {
+            return false;
         }
-        self.check_winner()?;
-        if self.ended {
-            return Ok(());
-        }
-        self.next_turn(2);
-        return Ok(());
+        let needs_deck = (!self.started || self.round_ended) && self.deck_root.is_none();
+        let call_card_hidden = self
+            .call_card
+            .is_some_and(|card| whot_engine::Card::from(card).is_hidden());
+        needs_deck || self.awaiting_reveal || (self.started && !self.round_ended && call_card_hidden)
     }
 
-    pub fn handle_suspension(&mut self) -> Result<()> {
-        self.check_winner()?;
-        if self.ended {
-            return Ok(());
-        }
-        self.next_turn(2);
-        Ok(())
+    /// Start the dealer's clock when the table starts waiting on it, stop it
+    /// once it no longer does
+    pub fn track_dealer(&mut self, now: i64) {
+        self.dealer_waiting_since = match self.waiting_for_dealer() {
+            true => self.dealer_waiting_since.or(Some(now)),
+            false => None
+        };
     }
 
-    pub fn handle_hold_on(&mut self) -> Result<()> {
-        self.check_winner()?;
-        if self.ended {
-            return Ok(());
-        }
-        Ok(())
+    /// Whether the dealer kept the table waiting past `DEALER_TIMEOUT`
+    pub fn dealer_overdue(&self, now: i64) -> bool {
+        self.waiting_for_dealer()
+            && self
+                .dealer_waiting_since
+                .is_some_and(|since| now >= since + DEALER_TIMEOUT)
     }
 
-    pub fn handle_general_market(&mut self) -> Result<()> {
-        // Ensure there is a draw pile
-        let draw_pile = self.draw_pile.as_mut().ok_or(GameErrors::NoDrawPile)?;
-
-        // Calculate number of players that should draw (excluding current player)
-        let total_players = self.no_players as usize;
-        let mut player_index = (self.player_turn - 1) as usize;
+    /// End a game the dealer stalled as a draw, without counting the round
+    pub fn call_off(&mut self, now: i64) {
+        self.ended = true;
+        self.winner = None;
+        self.winning_team = None;
+        self.awaiting_reveal = false;
+        self.player_turn = 0;
+        self.ended_at = Some(now);
+        self.dealer_waiting_since = None;
+    }
 
-        // Continue distributing cards until pile is exhausted or all eligible players have received one
-        for _ in 0..(total_players - 1) {
-            // Move to next player
-            player_index = (player_index % total_players) + 1;
-            if player_index as u8 == self.player_turn {
-                // Skip current player
-                player_index = (player_index % total_players) + 1;
-            }
+    /// Cards left in a seat's hand
+    pub fn hand_len(&self, seat: u8) -> u8 {
+        self.players
+            .get((seat as usize).wrapping_sub(1))
+            .and_then(|player| player.hand.as_ref())
+            .map_or(0, |hand| hand.len() as u8)
+    }
 
-            if draw_pile.is_empty() {
-                break;
-            }
+    pub fn market_len(&self) -> u8 {
+        self.draw_pile.as_ref().map_or(0, |pile| pile.len() as u8)
+    }
 
-            // Give one card to this player
-            let card = draw_pile.pop().unwrap();
-            if let Some(ref mut hand) = self.players[player_index - 1].hand {
-                hand.push(card);
-            }
-        }
+    /// Effect `card` has under this game's rules
+    pub fn effect_of(&self, card: Card) -> Option<whot_engine::Effect> {
+        whot_engine::RuleSet::from(self.rules).effect_of(&card.into())
+    }
 
-        self.check_winner()?;
-        if self.ended {
-            return Ok(());
+    /// Commitment to the deck dealt from the current random seed, or the
+    /// dealer's commitment when hands are hidden
+    pub fn deck_commitment(&self) -> Option<[u8; 32]> {
+        if self.rules.hidden_hands {
+            return self.deck_root;
         }
-        Ok(())
+        self.random_seed.map(whot_engine::deck_commitment::<SolanaHasher>)
     }
 
-    pub fn handle_need(&mut self) -> Result<()> {
-        self.check_winner()?;
-        if self.ended {
-            return Ok(());
-        }
+    /// Turn a face-down card up once it is proven against the dealer's deck
+    pub fn reveal_card(&mut self, card: Card, proof: &CardProof) -> Result<()> {
+        let root = self.deck_root.ok_or(GameErrors::DeckNotCommitted)?;
+        require!(
+            proof.opening(card).verify::<SolanaHasher>(&root),
+            GameErrors::InvalidDeckProof
+        );
+
+        let mut state = self.state();
+        state.reveal(proof.position, card.into()).map_err(GameErrors::from)?;
+        self.set_state(state);
         Ok(())
     }
 
-    pub fn handle_count_cards(&mut self) {
-        for player in &mut self.players {
-            let mut total_count: u8 = 0;
-
-            if let Some(ref hand) = player.hand {
-                for card in hand {
-                    if card.id == 6 {
-                        total_count =
-                            total_count.saturating_add(card.card_number.saturating_mul(2));
-                    } else {
-                        total_count = total_count.saturating_add(card.card_number);
-                    }
-                }
-            }
-
-            player.card_count = Some(total_count);
-        }
+    /// Turn up a face-down card of `seat`'s own hand, so that a seat can
+    /// only ever see the cards it was dealt
+    pub fn reveal_from_hand(&mut self, seat: u8, card: Card, proof: &CardProof) -> Result<()> {
+        let hidden = whot_engine::Card::hidden(proof.position);
+        require!(
+            self.state().player(seat).is_some_and(|player| player.hand.contains(&hidden)),
+            GameErrors::CardNotInHand
+        );
+        self.reveal_card(card, proof)
     }
 
-    pub fn handle_checkup(&mut self) {
-        if self.ended {
-            return;
-        }
-
-        self.handle_count_cards();
-        self.winner = Some(self.players[(self.player_turn - 1) as usize].owner);
-        self.ended = true;
-        self.ended_at = Some(Clock::get().unwrap().unix_timestamp);
-        self.player_turn = 0;
+    /// Turn the whole deck up from the dealer's secret once a hidden round
+    /// is over, then settle the round
+    pub fn reveal_deck(&mut self, secret: [u8; 32], cards: &[whot_engine::Card], now: i64) -> Result<()> {
+        let root = self.deck_root.ok_or(GameErrors::DeckNotCommitted)?;
+        require!(self.awaiting_reveal, GameErrors::RoundNotEnded);
+        require!(
+            whot_engine::verify_deck::<SolanaHasher>(&root, &secret, cards),
+            GameErrors::InvalidDeckProof
+        );
+
+        let mut state = self.state();
+        state.reveal_deck(cards, now).map_err(GameErrors::from)?;
+        self.set_state(state);
+        self.deck_root = None;
+        // the round was revealed, the next deck gets a clock of its own
+        self.dealer_waiting_since = None;
+        self.track_dealer(now);
+        Ok(())
     }
 
-    pub fn handle_market_finish(&mut self) {
-        if self.ended {
-            return;
-        }
-
-        self.handle_count_cards();
-
-        // Get the minimum card count across all players
-        let min_count = self
-            .players
-            .iter()
-            .filter_map(|p| p.card_count)
-            .min()
-            .unwrap_or(0);
+    pub fn legal_moves(&self, seat: u8, now: i64) -> LegalMoves {
+        self.state().legal_moves(seat, now).into()
+    }
 
-        // Collect all players who share that minimum count
-        let lowest_players: Vec<&Player> = self
+    /// Seat of a player once the cards have been dealt
+    pub fn seat_of(&self, owner: &Pubkey) -> Result<u8> {
+        let player = self
             .players
             .iter()
-            .filter(|p| p.card_count == Some(min_count))
-            .collect();
-
-        // Check if there's more than one player with the same lowest count
-        if lowest_players.len() > 1 {
-            self.winner = None; // No winner due to tie
-        } else {
-            self.winner = Some(lowest_players[0].owner);
-        }
-
-        self.ended = true;
-        self.ended_at = Some(Clock::get().unwrap().unix_timestamp);
-        self.player_turn = 0;
+            .find(|p| p.owner == *owner)
+            .ok_or(GameErrors::PlayerNotFound)?;
+        Ok(player.player_index.ok_or(GameErrors::GameNotStarted)?)
     }
 
-    pub fn handle_penalize_opponent(&mut self) -> Result<()> {
-        let current_index = (self.player_turn - 1) as usize;
-        let player = &mut self.players[current_index];
-
-        // Get current time in seconds
-        let now = Clock::get()?.unix_timestamp;
-
-        // Use last_move_time or fallback to game start time
-        let last_move = self
-            .last_move_time
-            .unwrap_or(self.started_at.unwrap_or(now));
+    /// Reward for the next `crank_timeout`. Rewards never add up to more
+    /// than the fee the pot pays, counted per seat so that no split of the
+    /// pot pays a smaller fee.
+    pub fn keeper_reward(&self, config: &Config) -> u64 {
+        let budget = self.players.len() as u64
+            * (self.entry_stake * self.platform_fee as u64 / 10_000);
+        (self.entry_stake * config.keeper_reward as u64 / 10_000)
+            .min(budget.saturating_sub(self.keeper_rewards))
+    }
 
-        // Only penalize if player exceeded wait_time
-        if now - last_move <= self.wait_time {
-            return Ok(()); // Not overdue, no penalty
+    /// Owe `keeper` a crank's reward, returns what was owed. Once
+    /// `MAX_KEEPERS` others are owed, new keepers crank for free.
+    pub fn reward_keeper(&mut self, keeper: Pubkey, reward: u64) -> u64 {
+        if reward == 0 {
+            return 0;
         }
+        match self.keepers.iter().position(|owed| owed.keeper == keeper) {
+            Some(index) => self.keepers[index].amount += reward,
+            None if self.keepers.len() < MAX_KEEPERS as usize => {
+                self.keepers.push(KeeperReward { keeper, amount: reward })
+            }
+            None => return 0
+        }
+        self.keeper_rewards += reward;
+        reward
+    }
 
-        // Ensure draw pile exists
-        let draw_pile = self.draw_pile.as_mut().ok_or(GameErrors::NoDrawPile)?;
+    /// Profile accounts of the seated players, in seat order
+    pub fn profiles(&self) -> impl Iterator<Item = Pubkey> + '_ {
+        self.players.iter().map(|player| {
+            Pubkey::find_program_address(&[PROFILE_SEED.as_bytes(), player.owner.as_ref()], &crate::ID).0
+        })
+    }
 
-        // Draw one card from draw pile to penalize current player
-        if let Some(ref mut hand) = player.hand {
-            if draw_pile.is_empty() {
-                self.handle_market_finish();
-                return Ok(());
-            }
+    /// Players owed a share of the pot, resigned players forfeit theirs
+    pub fn payees(&self) -> impl Iterator<Item = &Player> {
+        self.players
+            .iter()
+            .filter(|player| !player.out)
+            .filter(|player| match (self.winning_team, self.winner) {
+                (Some(team), _) => self.team_of(player) == Some(team),
+                (None, Some(winner)) => player.owner == winner,
+                (None, None) => true
+            })
+    }
 
-            let card = draw_pile.pop().unwrap();
-            hand.push(card);
-        }
+    /// Whether everyone owed a share of the pot has claimed it. Keepers
+    /// only have until the close grace period is over, their unclaimed
+    /// rewards are then swept to the fee wallet when the game is closed.
+    pub fn payouts_done(&self, now: i64) -> bool {
+        self.payees().all(|player| player.claimed)
+            && (self.keepers.is_empty() || self.close_grace_over(now))
+    }
 
-        self.check_winner()?;
-        if self.ended {
-            return Ok(());
-        }
-        self.next_turn(1);
+    /// Whether `CLOSE_GRACE_PERIOD` has passed since the game ended
+    pub fn close_grace_over(&self, now: i64) -> bool {
+        now >= self.ended_at.unwrap_or(self.created_at) + CLOSE_GRACE_PERIOD
+    }
 
-        Ok(())
+    pub fn team_of(&self, player: &Player) -> Option<u8> {
+        player.player_index.and_then(|seat| whot_engine::RuleSet::from(self.rules).team_of(seat))
     }
 }
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use crate::state::{Card, Game};
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, PartialEq)]
+pub enum GameStatus {
+    Lobby,          // waiting for players
+    Dealing,        // table is full, waiting for the deal
+    Playing,
+    AwaitingReveal, // hidden hands round is over, waiting for the dealer
+    RoundEnded,     // waiting for the next deal
+    Ended,
+    Cancelled       // called off before the first deal
+}
+
+/// A seat as a spectator sees it, the hand is only counted
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug, PartialEq)]
+pub struct SeatView {
+    pub owner: Pubkey,
+    pub username: String,
+    pub seat: Option<u8>,
+    pub hand_size: u8,
+    pub score: u16,
+    pub out: bool,
+    pub time_bank: i64, // 0 without a clock or once the bank ran out
+    pub timeouts: u8,
+    pub claimed: bool
+}
+
+/// Table view without any card identities, returned by `get_game_view`
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug, PartialEq)]
+pub struct GameView {
+    pub game: Pubkey,
+    pub creator: Pubkey,
+    pub owner: Pubkey,
+    pub stake_mint: Pubkey,
+    pub entry_stake: u64,
+    pub status: GameStatus,
+    pub no_players: u8,
+    pub seats: Vec<SeatView>,
+    pub round: u8,
+    pub rounds: u8,
+    pub player_turn: u8,
+    pub call_card: Option<Card>, // None while the call card is face down
+    pub requested_shape: Option<u8>,
+    pub pending_pick: u8,
+    pub draw_pile_size: u8,
+    pub discard_pile_size: u8,
+    pub wait_time: i64,
+    pub turn_deadline: Option<i64>, // seat on turn can be penalized after this
+    pub created_at: i64,
+    pub lobby_expires_at: i64,
+    pub started_at: Option<i64>,
+    pub ended_at: Option<i64>,
+    pub winner: Option<Pubkey>,
+    pub winning_team: Option<u8>
+}
+
+impl GameView {
+    pub fn new(key: Pubkey, game: &Game) -> Self {
+        let status = if game.ended && !game.started {
+            GameStatus::Cancelled
+        } else if game.ended {
+            GameStatus::Ended
+        } else if game.awaiting_reveal {
+            GameStatus::AwaitingReveal
+        } else if game.round_ended {
+            GameStatus::RoundEnded
+        } else if game.started {
+            GameStatus::Playing
+        } else if game.players.len() == game.no_players as usize {
+            GameStatus::Dealing
+        } else {
+            GameStatus::Lobby
+        };
+
+        GameView {
+            game: key,
+            creator: game.creator,
+            owner: game.owner,
+            stake_mint: game.stake_mint,
+            entry_stake: game.entry_stake,
+            status,
+            no_players: game.no_players,
+            seats: game
+                .players
+                .iter()
+                .map(|player| SeatView {
+                    owner: player.owner,
+                    username: player.username.to_owned(),
+                    seat: player.player_index,
+                    hand_size: player.hand.as_ref().map_or(0, |hand| hand.len() as u8),
+                    score: player.score,
+                    out: player.out,
+                    time_bank: player.time_bank,
+                    timeouts: player.timeouts,
+                    claimed: player.claimed
+                })
+                .collect(),
+            round: game.round,
+            rounds: game.rules.rounds,
+            player_turn: game.player_turn,
+            call_card: game
+                .call_card
+                .filter(|card| !whot_engine::Card::from(*card).is_hidden()),
+            requested_shape: game.requested_shape,
+            pending_pick: game.pending_pick,
+            draw_pile_size: game.market_len(),
+            discard_pile_size: game.discard_pile.len() as u8,
+            wait_time: game.wait_time,
+            turn_deadline: game.state().turn_deadline(),
+            created_at: game.created_at,
+            lobby_expires_at: game.lobby_expires_at,
+            started_at: game.started_at,
+            ended_at: game.ended_at,
+            winner: game.winner,
+            winning_team: game.winning_team
+        }
+    }
+
+    /// Decode the return data of a simulated `get_game_view` transaction
+    pub fn from_return_data(data: &[u8]) -> Result<Self> {
+        Ok(GameView::try_from_slice(data)?)
+    }
+
+    /// Build the view straight from fetched `Game` account data
+    pub fn from_account_data(key: Pubkey, mut data: &[u8]) -> Result<Self> {
+        let game = Game::try_deserialize(&mut data)?;
+        Ok(GameView::new(key, &game))
+    }
+}
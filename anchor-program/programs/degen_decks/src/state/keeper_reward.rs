@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+/// Reward owed to a keeper for cranking a game, paid out of the vault by
+/// `claim_keeper_reward` once the game is back on the base layer
+#[derive(InitSpace, AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, PartialEq)]
+pub struct KeeperReward {
+    pub keeper: Pubkey,
+    pub amount: u64
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::state::Card;
+
+/// Moves a seat can make, returned by `get_legal_moves`.
+/// A WHOT card in `cards` still needs a requested shape when played. In a
+/// hidden hands game the account only holds face-down positions, which are
+/// never listed, so `cards` stays empty and the client checks its own
+/// opened cards.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug, PartialEq)]
+pub struct LegalMoves {
+    pub cards: Vec<Card>,
+    pub can_draw: bool,
+    pub can_accept_penalty: bool,
+    pub out_of_time: bool // the seat can only resign or be penalized
+}
+
+impl From<whot_engine::LegalMoves> for LegalMoves {
+    fn from(legal_moves: whot_engine::LegalMoves) -> Self {
+        LegalMoves {
+            cards: legal_moves.cards.into_iter().map(Into::into).collect(),
+            can_draw: legal_moves.can_draw,
+            can_accept_penalty: legal_moves.can_accept_penalty,
+            out_of_time: legal_moves.out_of_time
+        }
+    }
+}
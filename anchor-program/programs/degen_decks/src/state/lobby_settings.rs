@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_LOBBY_TTL, MIN_LOBBY_TTL};
+
+/// How long a lobby stays open and who can join it, chosen by the owner at
+/// `initialize_game`. The profile limits keep fresh wallets from filling a
+/// table on their own.
+#[derive(InitSpace, AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct LobbySettings {
+    pub ttl: i64, // seconds before an unfilled lobby can be cancelled
+    pub min_profile_age: i64, // seconds since the joining profile was created
+    pub min_games_played: u32
+}
+
+impl LobbySettings {
+    pub fn is_valid(&self) -> bool {
+        (MIN_LOBBY_TTL..=MAX_LOBBY_TTL).contains(&self.ttl) && self.min_profile_age >= 0
+    }
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_STAKE_TIERS;
+use crate::state::Config;
+
+/// Stake limits of an allowed mint, set by the admin with `set_mint_config`.
+/// Games can only be created in a mint that has one.
+#[account]
+#[derive(InitSpace)]
+pub struct MintConfig {
+    pub mint: Pubkey,
+    pub min_stake: u64,
+    pub max_stake: u64,
+    #[max_len(MAX_STAKE_TIERS)]
+    pub stake_tiers: Vec<u64>, // when set, the only entry stakes allowed
+    pub platform_fee: Option<u16>, // overrides `Config::platform_fee` for games in this mint
+    pub bump: u8
+}
+
+impl MintConfig {
+    pub fn allows_stake(&self, entry_stake: u64) -> bool {
+        entry_stake > 0
+            && (self.min_stake..=self.max_stake).contains(&entry_stake)
+            && (self.stake_tiers.is_empty() || self.stake_tiers.contains(&entry_stake))
+    }
+
+    /// Fee in basis points taken from games staked in this mint
+    pub fn platform_fee(&self, config: &Config) -> u16 {
+        self.platform_fee.unwrap_or(config.platform_fee)
+    }
+}
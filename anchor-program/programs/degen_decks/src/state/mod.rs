@@ -1,6 +1,9 @@
 pub mod config;
 pub use config::*;
 
+pub mod mint_config;
+pub use mint_config::*;
+
 pub mod card;
 pub use card::*;
 
@@ -13,3 +16,26 @@ pub use profile::*;
 pub mod player;
 pub use player::*;
 
+pub mod rule_set;
+pub use rule_set::*;
+
+pub mod lobby_settings;
+pub use lobby_settings::*;
+
+pub mod keeper_reward;
+pub use keeper_reward::*;
+
+pub mod pause_flags;
+pub use pause_flags::*;
+
+pub mod legal_moves;
+pub use legal_moves::*;
+
+pub mod move_log;
+pub use move_log::*;
+
+pub mod card_proof;
+pub use card_proof::*;
+
+pub mod game_view;
+pub use game_view::*;
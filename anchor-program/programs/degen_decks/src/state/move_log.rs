@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use whot_engine::{Action, Move};
+
+use crate::constants::{MAX_LOGGED_DEALS, MAX_LOGGED_MOVES};
+use crate::errors::GameErrors;
+use crate::state::{Game, PackedCard};
+
+#[derive(InitSpace, AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, PartialEq)]
+pub enum MoveKind {
+    Play,
+    Draw,
+    AcceptPenalty,
+    Penalize,
+    Resign,
+    Timeout,
+    ResumeClock
+}
+
+#[derive(InitSpace, AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, PartialEq)]
+pub struct MoveRecord {
+    pub seat: u8,
+    pub kind: MoveKind,
+    pub card: Option<PackedCard>,
+    pub need: Option<u8>,
+    pub timestamp: i64
+}
+
+#[derive(InitSpace, AnchorDeserialize, AnchorSerialize, Clone, Debug, PartialEq)]
+pub struct DealRecord {
+    pub random_seed: u64,
+    pub timestamp: i64,
+    #[max_len(54)]
+    pub deck: Vec<PackedCard>, // revealed deck order of a hidden hands round
+    pub revealed_at: Option<i64>
+}
+
+/// Append-only record of every deal and move of a game, enough to replay
+/// it with `whot_engine::verify_replay`. A match is settled by count once
+/// it reaches `whot_engine::MAX_MOVES`, so the log never runs out of room.
+#[account]
+#[derive(InitSpace)]
+pub struct MoveLog {
+    pub game: Pubkey,
+    #[max_len(MAX_LOGGED_DEALS)]
+    pub deals: Vec<DealRecord>,
+    #[max_len(MAX_LOGGED_MOVES)]
+    pub moves: Vec<MoveRecord>,
+    pub bump: u8
+}
+
+impl MoveLog {
+    pub fn record_deal(&mut self, random_seed: u64, timestamp: i64) -> Result<()> {
+        require!(self.deals.len() < MAX_LOGGED_DEALS as usize, GameErrors::MoveLogFull);
+        self.deals.push(DealRecord { random_seed, timestamp, deck: vec![], revealed_at: None });
+        Ok(())
+    }
+
+    /// Keep the deck the dealer revealed for the current round
+    pub fn record_reveal(&mut self, deck: Vec<PackedCard>, timestamp: i64) -> Result<()> {
+        let deal = self.deals.last_mut().ok_or(GameErrors::GameNotStarted)?;
+        deal.deck = deck;
+        deal.revealed_at = Some(timestamp);
+        Ok(())
+    }
+
+    pub fn record_move(&mut self, mv: Move, timestamp: i64) -> Result<()> {
+        require!(self.moves.len() < MAX_LOGGED_MOVES as usize, GameErrors::MoveLogFull);
+        self.moves.push(MoveRecord::new(mv, timestamp));
+        Ok(())
+    }
+
+    /// Replay the log and check it reproduces `game`
+    pub fn verify(&self, game: &Game) -> std::result::Result<(), whot_engine::ReplayError> {
+        let deals: Vec<whot_engine::DealRecord> = self.deals.iter().cloned().map(Into::into).collect();
+        let moves: Vec<whot_engine::MoveRecord> = self.moves.iter().map(|record| (*record).into()).collect();
+        whot_engine::verify_replay_with::<crate::utils::SolanaHasher>(&game.state(), &deals, &moves)
+    }
+}
+
+impl MoveRecord {
+    pub fn new(mv: Move, timestamp: i64) -> Self {
+        let (kind, card, need) = match mv.action {
+            Action::Play { card, need } => (MoveKind::Play, Some(card.into()), need),
+            Action::Draw => (MoveKind::Draw, None, None),
+            Action::AcceptPenalty => (MoveKind::AcceptPenalty, None, None),
+            Action::Penalize => (MoveKind::Penalize, None, None),
+            Action::Resign => (MoveKind::Resign, None, None),
+            Action::Timeout => (MoveKind::Timeout, None, None),
+            Action::ResumeClock => (MoveKind::ResumeClock, None, None)
+        };
+        MoveRecord { seat: mv.seat, kind, card, need, timestamp }
+    }
+}
+
+impl From<MoveRecord> for whot_engine::MoveRecord {
+    fn from(record: MoveRecord) -> Self {
+        let action = match record.kind {
+            MoveKind::Play => Action::Play {
+                card: record.card.map(Into::into).unwrap_or_default(),
+                need: record.need
+            },
+            MoveKind::Draw => Action::Draw,
+            MoveKind::AcceptPenalty => Action::AcceptPenalty,
+            MoveKind::Penalize => Action::Penalize,
+            MoveKind::Resign => Action::Resign,
+            MoveKind::Timeout => Action::Timeout,
+            MoveKind::ResumeClock => Action::ResumeClock
+        };
+        whot_engine::MoveRecord { mv: Move::new(record.seat, action), timestamp: record.timestamp }
+    }
+}
+
+impl From<DealRecord> for whot_engine::DealRecord {
+    fn from(deal: DealRecord) -> Self {
+        whot_engine::DealRecord {
+            random_seed: deal.random_seed,
+            timestamp: deal.timestamp,
+            deck: deal.deck.into_iter().map(Into::into).collect(),
+            revealed_at: deal.revealed_at
+        }
+    }
+}
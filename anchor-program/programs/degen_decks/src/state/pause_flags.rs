@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+/// Circuit breaker switched by the admin with `set_paused`. Leaving an
+/// unstarted lobby is never paused.
+#[derive(InitSpace, AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct PauseFlags {
+    pub new_games: bool, // initialize_game
+    pub joins: bool, // join_game
+    pub moves: bool, // playing, drawing and penalizing turns
+    pub claims: bool // claim_prize
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::Card;
+use crate::state::PackedCard;
 
 #[derive(InitSpace, AnchorDeserialize, AnchorSerialize, Clone)]
 pub struct Player {
@@ -7,8 +7,12 @@ pub struct Player {
     #[max_len(32)]
     pub username: String,
     #[max_len(54)]
-    pub hand: Option<Vec<Card>>,
+    pub hand: Option<Vec<PackedCard>>, // packed, see `PackedCard`
     pub card_count: Option<u8>,
+    pub score: u16, // card counts summed over every round of the match
     pub player_index: Option<u8>,
+    pub out: bool, // resigned, forfeits the stake
+    pub time_bank: i64, // seconds left on the seat's clock, see `TimeControl`
+    pub timeouts: u8, // turns in a row the seat was penalized for running out of time
     pub claimed: bool
 }
\ No newline at end of file
@@ -8,5 +8,6 @@ pub struct Profile {
     pub total_won: u64,
     pub total_lost: u64,
     pub created_at: i64,
-    pub bump: u8
+    pub bump: u8,
+    pub games_played: u32 // games dealt, counted by `consume_randomness`
 }
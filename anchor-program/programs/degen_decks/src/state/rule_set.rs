@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+
+/// Fischer clock, mirrors `whot_engine::TimeControl`. Every seat starts the
+/// match with `bank` seconds, a move's time comes out of the bank and
+/// `increment` is added back after it.
+#[derive(InitSpace, AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, PartialEq)]
+pub struct TimeControl {
+    pub bank: i64,
+    pub increment: i64,
+    pub forfeit_on_flag: bool // an empty bank forfeits the seat instead of drawing a card
+}
+
+impl From<TimeControl> for whot_engine::TimeControl {
+    fn from(clock: TimeControl) -> Self {
+        whot_engine::TimeControl {
+            bank: clock.bank,
+            increment: clock.increment,
+            forfeit_on_flag: clock.forfeit_on_flag
+        }
+    }
+}
+
+impl From<whot_engine::TimeControl> for TimeControl {
+    fn from(clock: whot_engine::TimeControl) -> Self {
+        TimeControl {
+            bank: clock.bank,
+            increment: clock.increment,
+            forfeit_on_flag: clock.forfeit_on_flag
+        }
+    }
+}
+
+/// Rules a game is played with, chosen by the owner at `initialize_game`.
+/// Mirrors `whot_engine::RuleSet`, which also provides the classic,
+/// tournament and casual presets.
+/// A special card number set to `None` makes that number play as a neutral card.
+#[derive(InitSpace, AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, PartialEq)]
+pub struct RuleSet {
+    pub hand_size: u8,
+    pub hold_on: Option<u8>,
+    pub pick_two: Option<u8>,
+    pub pick_three: Option<u8>,
+    pub suspension: Option<u8>,
+    pub general_market: Option<u8>,
+    pub star_double: bool, // star cards count double when hands are counted
+    pub defend_picks: bool, // pick penalties can be defended and stacked
+    pub reshuffle_market: bool, // discard pile becomes the market when it runs out
+    pub rounds: u8, // rounds in the match, 1 plays a single hand
+    pub target_score: Option<u16>, // match ends early once a player reaches this score
+    pub team_mode: bool, // 2v2 with partners sitting opposite, needs 4 players
+    pub hidden_hands: bool, // hands are dealt face down against the dealer's deck commitment
+    pub time_control: Option<TimeControl>, // per seat clock, `wait_time` per move without one
+    pub max_timeouts: Option<u8> // a seat timed out this many turns in a row forfeits
+}
+
+impl RuleSet {
+    pub fn is_valid(&self, no_players: u8) -> bool {
+        whot_engine::RuleSet::from(*self).is_valid(no_players)
+    }
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        whot_engine::RuleSet::classic().into()
+    }
+}
+
+impl From<RuleSet> for whot_engine::RuleSet {
+    fn from(rules: RuleSet) -> Self {
+        whot_engine::RuleSet {
+            hand_size: rules.hand_size,
+            hold_on: rules.hold_on,
+            pick_two: rules.pick_two,
+            pick_three: rules.pick_three,
+            suspension: rules.suspension,
+            general_market: rules.general_market,
+            star_double: rules.star_double,
+            defend_picks: rules.defend_picks,
+            reshuffle_market: rules.reshuffle_market,
+            rounds: rules.rounds,
+            target_score: rules.target_score,
+            team_mode: rules.team_mode,
+            hidden_hands: rules.hidden_hands,
+            time_control: rules.time_control.map(Into::into),
+            max_timeouts: rules.max_timeouts
+        }
+    }
+}
+
+impl From<whot_engine::RuleSet> for RuleSet {
+    fn from(rules: whot_engine::RuleSet) -> Self {
+        RuleSet {
+            hand_size: rules.hand_size,
+            hold_on: rules.hold_on,
+            pick_two: rules.pick_two,
+            pick_three: rules.pick_three,
+            suspension: rules.suspension,
+            general_market: rules.general_market,
+            star_double: rules.star_double,
+            defend_picks: rules.defend_picks,
+            reshuffle_market: rules.reshuffle_market,
+            rounds: rules.rounds,
+            target_score: rules.target_score,
+            team_mode: rules.team_mode,
+            hidden_hands: rules.hidden_hands,
+            time_control: rules.time_control.map(Into::into),
+            max_timeouts: rules.max_timeouts
+        }
+    }
+}
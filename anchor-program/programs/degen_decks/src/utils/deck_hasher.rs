@@ -0,0 +1,12 @@
+use anchor_lang::solana_program::hash::hashv;
+use whot_engine::DeckHasher;
+
+/// Shuffles the deck with Solana's `hashv` syscall, producing the same
+/// deck as the engine's off-chain `Sha256` hasher
+pub struct SolanaHasher;
+
+impl DeckHasher for SolanaHasher {
+    fn hashv(vals: &[&[u8]]) -> [u8; 32] {
+        hashv(vals).to_bytes()
+    }
+}
@@ -1,5 +1,5 @@
 mod spl_transfer;
 pub use spl_transfer::*;
 
-mod shuffle_cards;
-pub use shuffle_cards::*;
\ No newline at end of file
+mod deck_hasher;
+pub use deck_hasher::*;
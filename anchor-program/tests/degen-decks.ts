@@ -124,11 +124,28 @@ describe("Degen Decks", () => {
         }
         throw new Error("Unknown player");
     }
-    function findValidCard(hand: any[], callCard: any): any | null {
+    // hands and piles are stored one byte per card: shape id in the top 3 bits,
+    // card number in the low 5 bits
+    function unpackCard(packed: any): { id: number, cardNumber: number } {
+        const byte = typeof packed === "number" ? packed : packed[0];
+        return { id: byte >> 5, cardNumber: byte & 0x1f };
+    }
+    function findValidCard(hand: any[], callCard: any, requestedShape: number | null, pendingPick: number): any | null {
         if (!hand || !callCard) return null;
-        return hand.find(
-            card => card.id === callCard.id || card.cardNumber === callCard.cardNumber
-        ) || null;
+        return hand.find(card => {
+            if (pendingPick > 0) return card.cardNumber === callCard.cardNumber;
+            if (card.id === 1) return true;
+            if (requestedShape !== null) return card.id === requestedShape;
+            return callCard.id === 1 || card.id === callCard.id || card.cardNumber === callCard.cardNumber;
+        }) || null;
+    }
+    // WHOT cards request the most common shape left in the player's hand
+    function findNeed(hand: any[], card: any): number | null {
+        if (card.id !== 1) return null;
+        const counts = [0, 0, 0, 0, 0, 0, 0];
+        hand.filter(c => c.id !== 1).forEach(c => counts[c.id]++);
+        const best = counts.indexOf(Math.max(...counts.slice(2)), 2);
+        return best;
     }
     async function getCurrentGameState() {
         return await program.account.game.fetch(game);
@@ -151,6 +168,8 @@ describe("Degen Decks", () => {
     const CONFIG_SEED = "CONFIG";
     const PROFILE_SEED = "PROFILE";
     const GAME_SEED = "GAME";
+    const MOVE_LOG_SEED = "MOVE_LOG";
+    const MINT_CONFIG_SEED = "MINT_CONFIG";
 
 
     // Game seeds
@@ -163,12 +182,14 @@ describe("Degen Decks", () => {
     // Accounts
     const user1 = provider.wallet;
     const feeWallet = user1.publicKey;
+    const dealer = user1.publicKey;
     const user2 = Keypair.generate();
     const user3 = Keypair.generate();
     const randomUser = Keypair.generate();
 
     // PDAs
     let config: PublicKey;
+    let mintConfig: PublicKey;
     let programData: PublicKey;
 
     let userProfile1: PublicKey;
@@ -184,6 +205,10 @@ describe("Degen Decks", () => {
         new BN(seed1).toArrayLike(Buffer, "le", 8),
         user1.publicKey.toBytes()
     ])[0];
+    const moveLog = findPDA([
+        Buffer.from(MOVE_LOG_SEED, "utf-8"),
+        game.toBytes()
+    ])[0];
     const feeWsolAta = getAssociatedTokenAddressSync(
         WSOL,
         feeWallet,
@@ -205,11 +230,44 @@ describe("Degen Decks", () => {
     const recordGame = delegationRecordPdaFromDelegatedAccount(
         game
     );
+    const bufferMoveLog = delegateBufferPdaFromDelegatedAccountAndOwnerProgram(
+        moveLog,
+        program.programId
+    );
+    const metadataMoveLog = delegationMetadataPdaFromDelegatedAccount(
+        moveLog
+    );
+    const recordMoveLog = delegationRecordPdaFromDelegatedAccount(
+        moveLog
+    );
 
     // Game variables
     const entryStake = 0.02 * LAMPORTS_PER_SOL;
     const noPlayers = 3;
     const waitTime = new BN(60);
+    const lobby = {
+        ttl: new BN(60 * 60),
+        minProfileAge: new BN(0),
+        minGamesPlayed: 0
+    };
+    // classic Nigerian Whot
+    const rules = {
+        handSize: 2,
+        holdOn: 1,
+        pickTwo: 2,
+        pickThree: 5,
+        suspension: 8,
+        generalMarket: 14,
+        starDouble: true,
+        defendPicks: true,
+        reshuffleMarket: false,
+        rounds: 1,
+        targetScore: null,
+        teamMode: false,
+        hiddenHands: false,
+        timeControl: null,
+        maxTimeouts: null
+    };
     let winner: PublicKey;
 
 
@@ -228,6 +286,7 @@ describe("Degen Decks", () => {
 
         // Derive PDAs
         config = findPDA([Buffer.from(CONFIG_SEED, "utf-8")])[0];
+        mintConfig = findPDA([Buffer.from(MINT_CONFIG_SEED, "utf-8"), WSOL.toBytes()])[0];
         userAta1 = await getOrCreateAssociatedTokenAccount(
             connection,
             user1.payer,
@@ -266,17 +325,19 @@ describe("Degen Decks", () => {
 
     describe("> Initialize Config", () => {
         const platformFee = 500; // 5%
+        const keeperReward = 50; // 0.5% of the entry stake per crank
         const allow_mints = [
             WSOL,
             USDC
         ];
         it("Should initialize the config", async () => {
             const tx = await program.methods
-                .initialize(platformFee, allow_mints)
+                .initialize(platformFee, keeperReward, allow_mints)
                 .accountsStrict({
                     admin: user1.publicKey,
                     config: config,
                     feeWallet: feeWallet,
+                    dealer: dealer,
                     programData: programData,
                     systemProgram: SystemProgram.programId,
                 })
@@ -286,29 +347,187 @@ describe("Degen Decks", () => {
 
             const configAccount = await program.account.config.fetch(config);
             expect(configAccount.platformFee).to.equal(platformFee, "Platform fees do not match");
+            expect(configAccount.keeperReward).to.equal(keeperReward, "Keeper reward does not match");
             expect(configAccount.allowedMints).to.deep.equal(allow_mints, "Allowed mints do not match");
+            expect(configAccount.dealer.toBase58()).to.equal(dealer.toBase58(), "Dealer does not match");
         });
 
-        it("Only admin should initialize", async () => {
+        it("Config should only be initialized once", async () => {
             const platformFee = 1000;
             try {
                 const tx = await program.methods
-                    .initialize(platformFee, allow_mints)
+                    .initialize(platformFee, keeperReward, allow_mints)
                     .accountsStrict({
-                        admin: randomUser.publicKey,
+                        admin: user1.publicKey,
                         config: config,
                         feeWallet: feeWallet,
+                        dealer: dealer,
                         programData: programData,
                         systemProgram: SystemProgram.programId,
                     })
-                    .signers([randomUser])
+                    .signers([user1.payer])
                     .rpc();
                 console.log("Initialize transaction", tx);
                 expect.fail("Expect instruction to throw");
+            } catch (error: any) {
+                expect(error.message).to.match(/already in use/i);
+            }
+        });
+
+        it("Config in the current layout should not be migrated", async () => {
+            try {
+                await program.methods
+                    .migrateConfig()
+                    .accountsStrict({
+                        admin: user1.publicKey,
+                        config: config,
+                        dealer: dealer,
+                        systemProgram: SystemProgram.programId,
+                        programData: programData,
+                    })
+                    .signers([user1.payer])
+                    .rpc();
+                expect.fail("Expect instruction to throw");
+            } catch (error: any) {
+                expect(error.message).to.match(/AlreadyMigrated/);
+            }
+        });
+
+        it("Admin should update the fees", async () => {
+            const tx = await program.methods
+                .updateFee(platformFee, keeperReward)
+                .accountsStrict({
+                    admin: user1.publicKey,
+                    config: config,
+                })
+                .signers([user1.payer])
+                .rpc();
+            console.log("Update Fee Transaction: ", tx);
+
+            const configAccount = await program.account.config.fetch(config);
+            expect(configAccount.admin.toBase58()).to.equal(user1.publicKey.toBase58(), "Admin does not match");
+            expect(configAccount.platformFee).to.equal(platformFee, "Platform fees do not match");
+        });
+
+        it("Admin should set the dealer", async () => {
+            await program.methods
+                .setDealer(dealer)
+                .accountsStrict({
+                    admin: user1.publicKey,
+                    config: config,
+                })
+                .signers([user1.payer])
+                .rpc();
+
+            const configAccount = await program.account.config.fetch(config);
+            expect(configAccount.dealer.toBase58()).to.equal(dealer.toBase58(), "Dealer does not match");
+        });
+
+        it("Admin should set the WSOL stake limits", async () => {
+            const minStake = new BN(0.01 * LAMPORTS_PER_SOL);
+            const maxStake = new BN(LAMPORTS_PER_SOL);
+            await program.methods
+                .setMintConfig(minStake, maxStake, [], null)
+                .accountsStrict({
+                    admin: user1.publicKey,
+                    config: config,
+                    stakeMint: WSOL,
+                    mintConfig: mintConfig,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers([user1.payer])
+                .rpc();
+
+            const mintConfigAccount = await program.account.mintConfig.fetch(mintConfig);
+            expect(mintConfigAccount.minStake.toNumber()).to.equal(minStake.toNumber(), "Min stake does not match");
+            expect(mintConfigAccount.maxStake.toNumber()).to.equal(maxStake.toNumber(), "Max stake does not match");
+            expect(mintConfigAccount.platformFee).to.be.null;
+        });
+
+        it("Only admin should update the config", async () => {
+            try {
+                const tx = await program.methods
+                    .removeAllowedMint(USDC)
+                    .accountsStrict({
+                        admin: randomUser.publicKey,
+                        config: config,
+                    })
+                    .signers([randomUser])
+                    .rpc();
+                console.log("Remove Mint Transaction: ", tx);
+                expect.fail("Expect instruction to throw");
             } catch (error: any) {
                 expect(error.message).to.match(/You Are Not Unauthorized/i);
             }
         });
+
+        it("Admin should pause and resume the program", async () => {
+            const paused = { newGames: true, joins: true, moves: false, claims: false };
+            await program.methods
+                .setPaused(paused)
+                .accountsStrict({
+                    admin: user1.publicKey,
+                    config: config,
+                })
+                .signers([user1.payer])
+                .rpc();
+            let configAccount = await program.account.config.fetch(config);
+            expect(configAccount.paused).to.deep.equal(paused, "Pause flags do not match");
+
+            await program.methods
+                .setPaused({ newGames: false, joins: false, moves: false, claims: false })
+                .accountsStrict({
+                    admin: user1.publicKey,
+                    config: config,
+                })
+                .signers([user1.payer])
+                .rpc();
+            configAccount = await program.account.config.fetch(config);
+            expect(configAccount.paused.newGames).to.equal(false, "Program is still paused");
+        });
+
+        it("Admin should be handed over in two steps", async () => {
+            await program.methods
+                .transferAdmin(user2.publicKey)
+                .accountsStrict({
+                    admin: user1.publicKey,
+                    config: config,
+                })
+                .signers([user1.payer])
+                .rpc();
+            let configAccount = await program.account.config.fetch(config);
+            expect(configAccount.admin.toBase58()).to.equal(user1.publicKey.toBase58(), "Admin changed before accepting");
+
+            await program.methods
+                .acceptAdmin()
+                .accountsStrict({
+                    newAdmin: user2.publicKey,
+                    config: config,
+                })
+                .signers([user2])
+                .rpc();
+            configAccount = await program.account.config.fetch(config);
+            expect(configAccount.admin.toBase58()).to.equal(user2.publicKey.toBase58(), "Admin does not match");
+            expect(configAccount.pendingAdmin).to.be.null;
+
+            // hand it back for the rest of the suite
+            await program.methods
+                .transferAdmin(user1.publicKey)
+                .accountsStrict({
+                    admin: user2.publicKey,
+                    config: config,
+                })
+                .signers([user2])
+                .rpc();
+            await program.methods
+                .acceptAdmin()
+                .accountsStrict({
+                    newAdmin: user1.publicKey,
+                    config: config,
+                })
+                .signers([user1.payer])
+                .rpc();
+        });
     });
 
     describe("> Initialize Profile", () => {
@@ -359,6 +578,25 @@ describe("Degen Decks", () => {
             expect(profileAccount2.username).to.equal(username2, "Username does not match");
             expect(profileAccount3.username).to.equal(username3, "Username does not match");
         });
+
+        it("Should leave a profile already in the current layout as it is", async () => {
+            const before = await connection.getAccountInfo(userProfile1);
+
+            await program.methods
+                .migrateProfile()
+                .accountsStrict({
+                    signer: user1.publicKey,
+                    profile: userProfile1,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers([user1.payer])
+                .rpc();
+
+            const after = await connection.getAccountInfo(userProfile1);
+            expect(after.data.length).to.equal(before.data.length, "Profile was resized");
+            const profileAccount = await program.account.profile.fetch(userProfile1);
+            expect(profileAccount.gamesPlayed).to.equal(0, "Games played does not match");
+        });
     });
 
     describe("> Initialize Game", () => {
@@ -372,16 +610,20 @@ describe("Degen Decks", () => {
                     seed1,
                     new BN(entryStake),
                     noPlayers,
-                    waitTime
+                    waitTime,
+                    rules,
+                    lobby
                 )
                 .accountsStrict({
                     signer: user1.publicKey,
                     profile: userProfile1,
                     game: game,
+                    moveLog: moveLog,
                     gameVault: gameVault,
                     stakeMint: WSOL,
                     userAta: userAta1.address,
                     config: config,
+                    mintConfig: mintConfig,
                     tokenProgram: TOKEN_PROGRAM_ID,
                     associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
                     systemProgram: SystemProgram.programId
@@ -410,6 +652,7 @@ describe("Degen Decks", () => {
             expect(gameAccount.playerTurn).to.equal(0, "Player turn does not match");
             expect(gameAccount.callCard).to.equal(null, "Call card does not match");
             expect(gameAccount.waitTime.toNumber()).to.equal(waitTime.toNumber(), "Wait time does not match");
+            expect(gameAccount.rules).to.deep.equal(rules, "Rules do not match");
             expect(gameAccount.seed.toNumber()).to.equal(seed1.toNumber(), "Seed does not match");
             expect(gameAccount.randomSeed).to.equal(null, "Random seed does not match");
             expect(gameAccount.delegated).to.equal(false, "Delegated does not match");
@@ -418,6 +661,7 @@ describe("Degen Decks", () => {
             expect(gameAccount.createdAt).to.equal(gameAccount.createdAt, "Created at does not match");
             expect(gameAccount.startedAt).to.equal(null, "Started at does not match");
             expect(gameAccount.endedAt).to.equal(null, "Ended at does not match");
+            expect(gameAccount.lobbyExpiresAt.sub(gameAccount.createdAt).toNumber()).to.equal(lobby.ttl.toNumber(), "Lobby expiry does not match");
         });
     });
 
@@ -433,6 +677,7 @@ describe("Degen Decks", () => {
     //                 signer: user2.publicKey,
     //                 profile: userProfile2,
     //                 game: game,
+    //                 moveLog: moveLog,
     //                 gameVault: gameVault,
     //                 stakeMint: WSOL,
     //                 userAta: userAta2.address,
@@ -472,6 +717,8 @@ describe("Degen Decks", () => {
     //                 signer: user2.publicKey,
     //                 profile: userProfile2,
     //                 game: game,
+    //                 moveLog: moveLog,
+    //                 creator: user1.publicKey,
     //                 gameVault: gameVault,
     //                 stakeMint: WSOL,
     //                 userAta: userAta2.address,
@@ -492,6 +739,35 @@ describe("Degen Decks", () => {
     // });
 
     describe("> User 2 and 3 joins Game", () => {
+        it("Owner should not take a second seat", async () => {
+            try {
+                await program.methods
+                    .joinGame()
+                    .accountsStrict({
+                        signer: user1.publicKey,
+                        profile: userProfile1,
+                        game: game,
+                        moveLog: moveLog,
+                        gameVault: gameVault,
+                        stakeMint: WSOL,
+                        userAta: userAta1.address,
+                        config: config,
+                        oracleQueue: new PublicKey("Cuj97ggrhhidhbu39TijNVqE74xvKJ69gDervRUXAxGh"),
+                        programIdentity: findPDA([Buffer.from("identity", "utf-8")])[0],
+                        vrfProgram: new PublicKey("Vrf1RNUjXmQGjmQrQLvJHs9SNkvDJEsRVFPkfSQUwGz"),
+                        slotHashes: new PublicKey("SysvarS1otHashes111111111111111111111111111"),
+                        tokenProgram: TOKEN_PROGRAM_ID,
+                        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .signers([user1.payer])
+                    .rpc();
+                expect.fail("Expect instruction to throw");
+            } catch (error: any) {
+                expect(error.message).to.match(/Already joined this game/i);
+            }
+        });
+
         it("User 2 Should Join game", async () => {
             let gameAccount = await program.account.game.fetch(game);
             let ataInfo = await getAccount(connection, userAta2.address);
@@ -503,6 +779,7 @@ describe("Degen Decks", () => {
                     signer: user2.publicKey,
                     profile: userProfile2,
                     game: game,
+                    moveLog: moveLog,
                     gameVault: gameVault,
                     stakeMint: WSOL,
                     userAta: userAta2.address,
@@ -545,6 +822,7 @@ describe("Degen Decks", () => {
                     signer: user3.publicKey,
                     profile: userProfile3,
                     game: game,
+                    moveLog: moveLog,
                     gameVault: gameVault,
                     stakeMint: WSOL,
                     userAta: userAta3.address,
@@ -578,6 +856,61 @@ describe("Degen Decks", () => {
             // console.log(gameAccount.players[0]);
             // console.log(gameAccount.players[1]);
         });
+
+        it("Spectators should see hand sizes only", async () => {
+            const view = await program.methods
+                .getGameView()
+                .accountsStrict({
+                    game: game,
+                })
+                .view();
+
+            const gameAccount = await program.account.game.fetch(game);
+            expect(view.seats.length).to.equal(3, "Seats length does not match");
+            view.seats.forEach((seat, i) => {
+                expect(seat.username).to.equal(gameAccount.players[i].username, "Username does not match");
+                expect(seat.handSize).to.equal((gameAccount.players[i].hand ?? []).length, "Hand size does not match");
+                expect(seat).to.not.have.property("hand");
+            });
+            expect(view.drawPileSize).to.equal((gameAccount.drawPile ?? []).length, "Draw pile size does not match");
+            expect(view.playerTurn).to.equal(gameAccount.playerTurn, "Player turn does not match");
+        });
+
+        it("Keepers should not crank a turn that is not overdue", async () => {
+            try {
+                const tx = await program.methods
+                    .crankTimeout()
+                    .accountsStrict({
+                        keeper: user1.publicKey,
+                        game: game,
+                        moveLog: moveLog,
+                        config: config,
+                    })
+                    .signers([user1.payer])
+                    .rpc();
+                console.log("Crank transaction", tx);
+                expect.fail("Expect instruction to throw");
+            } catch (error: any) {
+                expect(error.message).to.match(/Player on turn is not overdue yet/i);
+            }
+        });
+
+        it("Should not call off a game that is not waiting on the dealer", async () => {
+            try {
+                const tx = await program.methods
+                    .cancelStalledGame()
+                    .accountsStrict({
+                        signer: user1.publicKey,
+                        game: game,
+                    })
+                    .signers([user1.payer])
+                    .rpc();
+                console.log("Cancel stalled game transaction", tx);
+                expect.fail("Expect instruction to throw");
+            } catch (error: any) {
+                expect(error.message).to.match(/Dealer is not overdue yet/i);
+            }
+        });
     });
 
     describe("> Full Game Play", () => {
@@ -588,7 +921,9 @@ describe("Degen Decks", () => {
                 const currentPlayer = gameAccount.players[currentPlayerIndex];
                 const { signer, profile, keypair } = getPlayerCredentials(currentPlayer.owner);
 
-                const validCard = findValidCard(currentPlayer.hand, gameAccount.callCard);
+                const hand = (currentPlayer.hand ?? []).map(unpackCard);
+                const validCard = findValidCard(hand, gameAccount.callCard, gameAccount.requestedShape, gameAccount.pendingPick);
+                const need = validCard ? findNeed(hand, validCard) : null;
 
                 const providerEphemeralRollup = new anchor.AnchorProvider(
                     new anchor.web3.Connection(
@@ -610,26 +945,33 @@ describe("Degen Decks", () => {
                     if (validCard && !forceDrawIfNoCard) {
                         if (gameAccount.delegated) {
                             await programEphemeralRollup.methods
-                                .playCard(validCard)
+                                .playCard(validCard, need, null)
                                 .accountsStrict({
                                     signer,
                                     profile,
                                     game,
+                                    moveLog,
+                                    config,
                                 })
                                 .rpc();
                             console.log(`${currentPlayer.username} played ${validCard.cardNumber} ${cards[validCard.id - 1]}`);
                         } else {
                             console.log("Undelegated Play")
                             let tx = await program.methods
-                                .playCardDelegate(validCard)
+                                .playCardDelegate(validCard, need, null)
                                 .accountsStrict({
                                     signer,
                                     profile,
                                     game,
+                                    moveLog,
+                                    config,
                                     ownerProgram: program.programId,
                                     bufferGame: bufferGame,
                                     delegationRecordGame: recordGame,
                                     delegationMetadataGame: metadataGame,
+                                    bufferMoveLog: bufferMoveLog,
+                                    delegationRecordMoveLog: recordMoveLog,
+                                    delegationMetadataMoveLog: metadataMoveLog,
                                     delegationProgram: DELEGATION_PROGRAM_ID,
                                     systemProgram: SystemProgram.programId,
                                 })
@@ -641,6 +983,20 @@ describe("Degen Decks", () => {
 
                             console.log(`${currentPlayer.username} played ${validCard.cardNumber} ${cards[validCard.id - 1]}`);
                         }
+                    } else if (gameAccount.pendingPick > 0) {
+                        const penaltyProgram = gameAccount.delegated ? programEphemeralRollup : program;
+                        await penaltyProgram.methods
+                            .acceptPenalty()
+                            .accountsStrict({
+                                signer,
+                                profile,
+                                game,
+                                moveLog,
+                                config
+                            })
+                            .signers([keypair])
+                            .rpc();
+                        console.log(`${currentPlayer.username} picked ${gameAccount.pendingPick} cards`);
                     } else {
                         if (gameAccount.delegated) {
                             await programEphemeralRollup.methods
@@ -648,7 +1004,9 @@ describe("Degen Decks", () => {
                                 .accountsStrict({
                                     signer,
                                     profile,
-                                    game
+                                    game,
+                                    moveLog,
+                                    config
                                 })
                                 .rpc();
                             console.log(`${currentPlayer.username} drew from draw pile`);
@@ -659,10 +1017,15 @@ describe("Degen Decks", () => {
                                     signer,
                                     profile,
                                     game,
+                                    moveLog,
+                                    config,
                                     ownerProgram: program.programId,
                                     bufferGame: bufferGame,
                                     delegationRecordGame: recordGame,
                                     delegationMetadataGame: metadataGame,
+                                    bufferMoveLog: bufferMoveLog,
+                                    delegationRecordMoveLog: recordMoveLog,
+                                    delegationMetadataMoveLog: metadataMoveLog,
                                     delegationProgram: DELEGATION_PROGRAM_ID,
                                     systemProgram: SystemProgram.programId,
                                 })
@@ -697,10 +1060,18 @@ describe("Degen Decks", () => {
                 .accountsStrict({
                     signer: user1.publicKey,
                     game: game,
+                    moveLog: moveLog,
                     magicContext: MAGIC_CONTEXT_ID,
                     magicProgram: MAGIC_PROGRAM_ID
                 })
                 .rpc();
+
+            // every round was dealt and logged, so the game can be replayed off-chain
+            const gameAccount = await getCurrentGameStateER();
+            const moveLogAccount = await programEphemeralRollup.account.moveLog.fetch(moveLog);
+            expect(moveLogAccount.game.toBase58()).to.equal(game.toBase58(), "Move log game does not match");
+            expect(moveLogAccount.deals.length).to.equal(gameAccount.round, "Logged deals do not match rounds");
+            expect(moveLogAccount.moves.length).to.be.greaterThan(0, "No moves were logged");
         })
     })
 
@@ -753,4 +1124,34 @@ describe("Degen Decks", () => {
         })
     })
 
+    describe("> Close Game", () => {
+        it("Owner should close the paid out game and reclaim rent", async () => {
+            if (winner) {
+                const ownerBalance = await connection.getBalance(user1.publicKey);
+
+                const tx = await program.methods
+                    .closeGame()
+                    .accountsStrict({
+                        signer: user1.publicKey,
+                        creator: user1.publicKey,
+                        game: game,
+                        moveLog: moveLog,
+                        gameVault: gameVault,
+                        feeAta: feeWsolAta,
+                        config: config,
+                        stakeMint: WSOL,
+                        tokenProgram: TOKEN_PROGRAM_ID
+                    })
+                    .signers([user1.payer])
+                    .rpc();
+                console.log("close tx", tx);
+
+                expect(await connection.getAccountInfo(game)).to.equal(null, "Game was not closed");
+                expect(await connection.getAccountInfo(moveLog)).to.equal(null, "Move log was not closed");
+                expect(await connection.getAccountInfo(gameVault)).to.equal(null, "Game vault was not closed");
+                expect(await connection.getBalance(user1.publicKey)).to.be.greaterThan(ownerBalance, "Rent was not returned");
+            }
+        })
+    })
+
 }); 
\ No newline at end of file